@@ -1,15 +1,19 @@
 use iced::{
-    button, executor, text_input, Application, Button, Column, Container, Element, Length, Row,
-    Settings, Subscription, Text, TextInput,
+    executor, keyboard::KeyCode, text_input, Application, Button, Column, Container, Element,
+    Length, Row, Settings, Subscription, Text, TextInput,
 };
+use iced_focus::widget::{Action, DefaultStyle, FocusableButton};
 use iced_focus::Focus;
 //use iced_focus_derive::Focus;
 
 #[derive(Debug, Focus)]
 struct EnumsExample {
-    button_none: button::State,
-    button_one: button::State,
-    button_two: button::State,
+    #[focus(enable)]
+    button_none: FocusableButton,
+    #[focus(enable)]
+    button_one: FocusableButton,
+    #[focus(enable)]
+    button_two: FocusableButton,
 
     #[focus(enable)]
     enum_state: EnumState,
@@ -21,6 +25,7 @@ pub enum Message {
     One(String),
     Two(String),
     Focus(iced_focus::Direction),
+    Key(KeyCode),
 }
 
 fn main() -> iced::Result {
@@ -35,9 +40,9 @@ impl Application for EnumsExample {
     fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
         (
             EnumsExample {
-                button_none: button::State::new(),
-                button_one: button::State::new(),
-                button_two: button::State::new(),
+                button_none: FocusableButton::default(),
+                button_one: FocusableButton::default(),
+                button_two: FocusableButton::default(),
                 enum_state: EnumState::None,
             },
             iced::Command::none(),
@@ -51,7 +56,7 @@ impl Application for EnumsExample {
     fn update(
         &mut self,
         message: Self::Message,
-        _clipboard: &mut iced::Clipboard,
+        clipboard: &mut iced::Clipboard,
     ) -> iced::Command<Self::Message> {
         match message {
             Message::Show(variant) => match variant {
@@ -72,30 +77,36 @@ impl Application for EnumsExample {
             Message::Focus(direction) => {
                 let _ = self.focus(direction);
             }
+            Message::Key(key_code) => {
+                let pressed = [
+                    (&self.button_none, EnumVariant::None),
+                    (&self.button_one, EnumVariant::One),
+                    (&self.button_two, EnumVariant::Two),
+                ]
+                .iter()
+                .find(|(button, _)| button.action(key_code) == Some(Action::Press))
+                .map(|(_, variant)| *variant);
+
+                if let Some(variant) = pressed {
+                    return self.update(Message::Show(variant), clipboard);
+                }
+            }
         }
 
         iced::Command::none()
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        iced_native::subscription::events_with(|event, _status| {
-            if let iced_native::Event::Keyboard(iced::keyboard::Event::KeyPressed {
-                key_code: iced_native::keyboard::KeyCode::Tab,
-                modifiers,
-            }) = event
-            {
-                Some(Message::Focus(if modifiers.shift {
-                    iced_focus::Direction::Backwards
-                } else {
-                    iced_focus::Direction::Forwards
-                }))
-            } else {
-                None
-            }
-        })
+        Subscription::batch(vec![
+            iced_focus::subscription::keyboard(Message::Focus),
+            iced_focus::subscription::keys(Message::Key),
+        ])
     }
 
     fn view(&mut self) -> Element<'_, Self::Message> {
+        let none_style = self.button_none.indicator(DefaultStyle);
+        let one_style = self.button_one.indicator(DefaultStyle);
+        let two_style = self.button_two.indicator(DefaultStyle);
         let content = self.enum_state.view();
 
         Container::new(
@@ -105,14 +116,17 @@ impl Application for EnumsExample {
                         .spacing(5)
                         .push(
                             Button::new(&mut self.button_none, Text::new("None"))
+                                .style(none_style)
                                 .on_press(Message::Show(EnumVariant::None)),
                         )
                         .push(
                             Button::new(&mut self.button_one, Text::new("One"))
+                                .style(one_style)
                                 .on_press(Message::Show(EnumVariant::One)),
                         )
                         .push(
                             Button::new(&mut self.button_two, Text::new("Two"))
+                                .style(two_style)
                                 .on_press(Message::Show(EnumVariant::Two)),
                         ),
                 )
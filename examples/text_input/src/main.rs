@@ -88,21 +88,7 @@ impl Application for TextInputFocusExample {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        iced_native::subscription::events_with(|event, _status| {
-            if let iced_native::Event::Keyboard(iced::keyboard::Event::KeyPressed {
-                key_code: iced_native::keyboard::KeyCode::Tab,
-                modifiers,
-            }) = event
-            {
-                Some(Message::Focus(if modifiers.shift {
-                    iced_focus::Direction::Backwards
-                } else {
-                    iced_focus::Direction::Forwards
-                }))
-            } else {
-                None
-            }
-        })
+        iced_focus::subscription::keyboard(Message::Focus)
     }
 
     fn view(&mut self) -> iced::Element<'_, Self::Message> {
@@ -44,13 +44,17 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::ext::IdentExt;
 
 /// The starting point of the procedural macro.
 #[proc_macro_derive(Focus, attributes(focus))]
 pub fn focus_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap();
+    let ast = match syn::parse(input) {
+        Ok(ast) => ast,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     // Build the trait implementation
     impl_focus(&ast)
@@ -59,12 +63,173 @@ pub fn focus_derive(input: TokenStream) -> TokenStream {
 /// Implement the `Focus` trait for the given AST.
 fn impl_focus(ast: &syn::DeriveInput) -> TokenStream {
     let ident = &ast.ident;
-    let generics = &ast.generics;
+    let container = match ContainerAttributes::extract_container_attributes(&ast.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let generics = &container.add_bounds(&ast.generics);
+
+    let mut result = match ast.data {
+        syn::Data::Struct(ref s) => impl_focus_struct(ident, generics, &container, s),
+        syn::Data::Enum(ref e) => impl_focus_enum(ident, generics, &container, e),
+        syn::Data::Union(ref u) => {
+            return syn::Error::new_spanned(u.union_token, "Unions are currently not supported.")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    if container.fields {
+        result.extend(TokenStream::from(build_focus_field_enum(
+            ast, generics, &container,
+        )));
+    }
+    result
+}
+
+/// Returns the identifier of a local variable or parameter of the generated code.
+///
+/// The identifier is resolved at the mixed site, so that it can't be shadowed by the fields of
+/// the struct or enum and isn't visible to the annotated predicates.
+fn local(name: &str) -> syn::Ident {
+    syn::Ident::new(name, proc_macro2::Span::mixed_site())
+}
+
+/// Returns the identifier of the evaluated enable predicate of a field.
+fn boolean_ident(field_index: usize, variant_index: Option<usize>) -> syn::Ident {
+    match variant_index {
+        Some(variant_index) => local(&format!("b_{}_{}", variant_index, field_index)),
+        None => local(&format!("b_{}", field_index)),
+    }
+}
+
+/// Returns the identifier binding the field with the given index inside of an enum variant.
+fn field_binding(index: usize) -> syn::Ident {
+    local(&format!("field_{}", index))
+}
+
+/// Build the companion enum listing the focusable fields and the methods to focus them.
+///
+/// Invalid fields are already reported by the implementation of the `Focus` trait, so no
+/// companion is built for them. Fields that are only included by `focus(auto)` might not
+/// implement `Focus`, so they are left out. Fields that are converted to the same variant are
+/// reported as an error.
+fn build_focus_field_enum(
+    ast: &syn::DeriveInput,
+    generics: &syn::Generics,
+    container: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+    let fields: syn::Result<Vec<(Option<&syn::Ident>, Vec<FocusField>)>> = match ast.data {
+        syn::Data::Struct(ref s) if !matches!(s.fields, syn::Fields::Unit) => {
+            FocusField::collect_fields(&s.fields, container.auto).map(|fields| vec![(None, fields)])
+        }
+        syn::Data::Enum(ref e) => e
+            .variants
+            .iter()
+            .map(|variant| {
+                FocusField::collect_fields(&variant.fields, container.auto)
+                    .map(|fields| (Some(&variant.ident), fields))
+            })
+            .collect(),
+        syn::Data::Struct(_) | syn::Data::Union(_) => return quote! {},
+    };
+    let fields = match fields {
+        Ok(fields) => fields,
+        Err(_) => return quote! {},
+    };
+
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let enum_ident = syn::Ident::new(&format!("{}FocusField", ident), ident.span());
+    let enum_doc = format!("The focusable fields of [`{0}`]({0}).", ident);
+
+    let mut names = Vec::new();
+    let mut variants = Vec::new();
+    let mut focus_arms = Vec::new();
+    let mut focused_arms = Vec::new();
+
+    for (variant, fields) in &fields {
+        for field in fields.iter().filter(|field| !field.auto) {
+            let name = field.companion_name(*variant);
+
+            // Different fields can be converted to the same name, e.g. `a_b` and `aB`.
+            if names.contains(&name) {
+                return syn::Error::new(
+                    field.span,
+                    format!(
+                        "The variant `{}` of `{}` is used by more than one field. Rename this field.",
+                        name, enum_ident
+                    ),
+                )
+                .to_compile_error();
+            }
+            names.push(name.clone());
+            let doc = format!("The field `{}`.", field.ident);
+            let segment = &field.segment;
+
+            variants.push(quote! {
+                #[doc = #doc]
+                #name,
+            });
 
-    match ast.data {
-        syn::Data::Struct(ref s) => impl_focus_struct(ident, generics, s),
-        syn::Data::Enum(ref e) => impl_focus_enum(ident, generics, e),
-        syn::Data::Union(ref _u) => unimplemented!("Unions are currently not supported."),
+            if let Some(variant) = variant {
+                focus_arms.push(quote! {
+                    #enum_ident::#name => if matches!(self, Self::#variant { .. }) {
+                        iced_focus::Focus::focus_path(self, &[#segment])
+                    } else {
+                        iced_focus::State::Ignored
+                    },
+                });
+                focused_arms.push(quote! {
+                    (Self::#variant { .. }, Some(#segment)) => Some(#enum_ident::#name),
+                });
+            } else {
+                focus_arms.push(quote! {
+                    #enum_ident::#name => iced_focus::Focus::focus_path(self, &[#segment]),
+                });
+                focused_arms.push(quote! {
+                    (_, Some(#segment)) => Some(#enum_ident::#name),
+                });
+            }
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[allow(dead_code, clippy::enum_variant_names)]
+        #vis enum #enum_ident {
+            #(#variants)*
+        }
+
+        #[allow(dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Request the focus of the given field and remove the focus from every other element.
+            ///
+            /// The request is ignored, if the field is disabled or belongs to another variant.
+            #vis fn focus_field(&mut self, field: #enum_ident) -> iced_focus::State {
+                match field {
+                    #(#focus_arms)*
+                }
+            }
+
+            /// Returns the field that has the focus or contains the element that has it.
+            #vis fn focused_field(&self) -> Option<#enum_ident> {
+                let path = iced_focus::Focus::focused_path(self)?;
+
+                match (self, path.first()) {
+                    #(#focused_arms)*
+                    _ => None,
+                }
+            }
+
+            /// True, if the given field has the focus or contains the element that has it.
+            #vis fn is_field_focused(&self, field: #enum_ident) -> bool {
+                self.focused_field() == Some(field)
+            }
+        }
     }
 }
 
@@ -72,94 +237,176 @@ fn impl_focus(ast: &syn::DeriveInput) -> TokenStream {
 fn impl_focus_struct(
     ident: &syn::Ident,
     generics: &syn::Generics,
+    container: &ContainerAttributes,
     s: &syn::DataStruct,
 ) -> TokenStream {
-    let (fields, len) = match s.fields {
-        syn::Fields::Named(ref named) => {
-            (FocusField::collect_fields_named(named), named.named.len())
-        }
-        syn::Fields::Unnamed(ref unnamed) => (
-            FocusField::collect_fields_unnamed(unnamed),
-            unnamed.unnamed.len(),
-        ),
-        syn::Fields::Unit => unimplemented!("Unit structs are currently not supported."),
+    let fields = match s.fields {
+        syn::Fields::Named(_) | syn::Fields::Unnamed(_) => {
+            FocusField::collect_fields(&s.fields, container.auto)
+        }
+        syn::Fields::Unit => Err(syn::Error::new_spanned(
+            ident,
+            "Unit structs are currently not supported.",
+        )),
     };
 
-    build_focus_trait_for_struct(ident, generics, &fields, len)
+    let fields = fields.and_then(|fields| {
+        MemoryField::check_group(container, ident, &s.fields, &fields).map(|()| fields)
+    });
+    let memory = MemoryField::find_memory_field(&s.fields);
+
+    match fields {
+        Ok(fields) => {
+            build_focus_trait_for_struct(ident, generics, container, &fields, memory.as_ref())
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
 }
 
 /// Build the token stream of the trait implementation for a struct.
-fn build_focus_trait_for_struct<'a>(
+fn build_focus_trait_for_struct(
     ident: &syn::Ident,
     generics: &syn::Generics,
-    fields: &[FocusField<'a>],
-    len: usize,
+    container: &ContainerAttributes,
+    fields: &[FocusField],
+    memory: Option<&MemoryField>,
 ) -> TokenStream {
-    let array_name = quote! {fields};
-    let focus_method_body = build_focus_method_body(0, &array_name, fields, len, true);
-    let has_focus_method_body = build_has_focus_method_body(fields, true);
+    let array_name = local("fields");
+    let focus_method_body =
+        build_focus_method_body(0, &array_name, container, fields, memory, true);
+    let has_focus_method_body = build_any_field_method_body(fields, "has_focus", true);
+    let focus_path_method_body = build_focus_path_method_body(0, &array_name, fields, true);
+    let focused_path_method_body = build_focused_path_method_body(fields, true);
+    let all_paths_method_body = build_focused_paths_method_body(fields, true);
+    let disabled_paths_method_body = build_disabled_focused_paths_method_body(fields, true);
+    let blur_method_body = build_blur_method_body(
+        0,
+        &array_name,
+        memory.filter(|_| container.group),
+        fields,
+        true,
+    );
+    let is_trapping_method_body = build_any_field_method_body(fields, "is_trapping", true);
+    let has_focus_at_method_body = build_has_focus_at_method_body(fields, true);
+    let sanitize_method_body = build_sanitize_method_body(0, fields, true);
+    let focus_from_method_body = if container.group || container.cycle {
+        build_focus_from_fallback()
+    } else {
+        build_focus_from_method_body(0, &array_name, fields, true)
+    };
 
-    let generic_idents = generic_idents(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let direction = local("direction");
+    let path = local("path");
+    let current = local("current");
 
     let result = quote! {
-        impl#generics iced_focus::Focus for #ident#generic_idents {
-            fn focus(&mut self, direction: iced_focus::Direction) -> iced_focus::State {
+        impl #impl_generics iced_focus::Focus for #ident #ty_generics #where_clause {
+            fn focus(&mut self, #direction: iced_focus::Direction) -> iced_focus::State {
+                iced_focus::Focus::sanitize(self);
+
                 #focus_method_body
             }
 
             fn has_focus(&self) -> bool {
                 #has_focus_method_body
             }
+
+            fn focus_path(&mut self, #path: &[iced_focus::PathSegment]) -> iced_focus::State {
+                #focus_path_method_body
+            }
+
+            fn focused_path(&self) -> Option<iced_focus::FocusPath> {
+                #focused_path_method_body
+            }
+
+            fn focused_paths(&self) -> Vec<iced_focus::FocusPath> {
+                #all_paths_method_body
+            }
+
+            fn disabled_focused_paths(&self) -> Vec<iced_focus::FocusPath> {
+                #disabled_paths_method_body
+            }
+
+            fn blur(&mut self) {
+                #blur_method_body
+            }
+
+            fn is_trapping(&self) -> bool {
+                #is_trapping_method_body
+            }
+
+            fn has_focus_at(&self, #path: &[iced_focus::PathSegment]) -> bool {
+                #has_focus_at_method_body
+            }
+
+            fn focus_from(
+                &mut self,
+                #current: &[iced_focus::PathSegment],
+                #direction: iced_focus::Direction,
+            ) -> (iced_focus::State, Option<iced_focus::FocusPath>) {
+                #focus_from_method_body
+            }
+
+            fn sanitize(&mut self) {
+                #sanitize_method_body
+            }
         }
     };
     result.into()
 }
 
 /// Implement the `Focus` trait for an enum.
-fn impl_focus_enum(ident: &syn::Ident, generics: &syn::Generics, e: &syn::DataEnum) -> TokenStream {
+fn impl_focus_enum(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    container: &ContainerAttributes,
+    e: &syn::DataEnum,
+) -> TokenStream {
     let variants = &e.variants;
 
-    let method_bodies: Vec<(proc_macro2::TokenStream, proc_macro2::TokenStream)> = variants
+    let variant_fields: syn::Result<Vec<Vec<FocusField>>> = variants
         .iter()
-        .enumerate()
-        .map(|(index, variant)| impl_focus_enum_variant(index, variant))
+        .map(|variant| FocusField::collect_fields(&variant.fields, container.auto))
         .collect();
+    let variant_fields = match variant_fields {
+        Ok(variant_fields) => variant_fields,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
-    let focus_bodies = method_bodies.iter().map(|(focus, _)| focus);
-    let has_focus_bodies = method_bodies.iter().map(|(_, has_focus)| has_focus);
+    let arms = match build_enum_arms(container, variants, &variant_fields) {
+        Ok(arms) => arms,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
-    // TODO: clean this up.
-    let booleans: Vec<proc_macro2::TokenStream> = variants
-        .iter()
-        .enumerate()
-        .map(|(index, variant)| {
-            let fields = match variant.fields {
-                syn::Fields::Named(ref named) => FocusField::collect_fields_named(named),
-                syn::Fields::Unnamed(ref unnamed) => FocusField::collect_fields_unnamed(unnamed),
-                syn::Fields::Unit => Vec::new(),
-            };
+    let focus_bodies = arms.iter().map(|arms| &arms.focus);
+    let has_focus_bodies = arms.iter().map(|arms| &arms.has_focus);
+    let focus_path_bodies = arms.iter().map(|arms| &arms.focus_path);
+    let focused_path_bodies = arms.iter().map(|arms| &arms.focused_path);
+    let all_paths_bodies = arms.iter().map(|arms| &arms.focused_paths);
+    let disabled_paths_bodies = arms.iter().map(|arms| &arms.disabled_focused_paths);
+    let blur_bodies = arms.iter().map(|arms| &arms.blur);
+    let is_trapping_bodies = arms.iter().map(|arms| &arms.is_trapping);
+    let has_focus_at_bodies = arms.iter().map(|arms| &arms.has_focus_at);
+    let sanitize_bodies = arms.iter().map(|arms| &arms.sanitize);
 
-            let booleans = fields
-                .iter()
-                .map(|field| (field.index, &field.attribute))
-                .filter_map(|(field_index, attribute)| match attribute {
-                    FocusAttribute::Enable(_) => None,
-                    FocusAttribute::EnableWith(_, _) => {
-                        Some(attribute.to_boolean_expression(field_index, Some(index)))
-                    }
-                });
+    let booleans = build_enum_booleans(&variant_fields);
 
-            quote! {
-                #(#booleans)*
-            }
-        })
-        .collect();
+    let focus_from_method_body = build_enum_focus_from_method_body(container, &booleans, &arms);
+
+    // The fields array of a group needs the predicates to remember the focused element.
+    let blur_booleans = if container.group { &booleans[..] } else { &[] };
 
-    let generic_idents = generic_idents(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let direction = local("direction");
+    let path = local("path");
+    let current = local("current");
 
     let result = quote! {
-        impl#generics iced_focus::Focus for #ident#generic_idents {
-            fn focus(&mut self, direction: iced_focus::Direction) -> iced_focus::State {
+        impl #impl_generics iced_focus::Focus for #ident #ty_generics #where_clause {
+            fn focus(&mut self, #direction: iced_focus::Direction) -> iced_focus::State {
+                iced_focus::Focus::sanitize(self);
+
                 #(#booleans)*
 
                 match self {
@@ -172,70 +419,621 @@ fn impl_focus_enum(ident: &syn::Ident, generics: &syn::Generics, e: &syn::DataEn
                     #(#has_focus_bodies)*
                 }
             }
+
+            fn focus_path(&mut self, #path: &[iced_focus::PathSegment]) -> iced_focus::State {
+                #(#booleans)*
+
+                match self {
+                    #(#focus_path_bodies)*
+                }
+            }
+
+            fn focused_path(&self) -> Option<iced_focus::FocusPath> {
+                match self {
+                    #(#focused_path_bodies)*
+                }
+            }
+
+            fn focused_paths(&self) -> Vec<iced_focus::FocusPath> {
+                match self {
+                    #(#all_paths_bodies)*
+                }
+            }
+
+            fn disabled_focused_paths(&self) -> Vec<iced_focus::FocusPath> {
+                match self {
+                    #(#disabled_paths_bodies)*
+                }
+            }
+
+            fn blur(&mut self) {
+                #(#blur_booleans)*
+
+                match self {
+                    #(#blur_bodies)*
+                }
+            }
+
+            fn is_trapping(&self) -> bool {
+                match self {
+                    #(#is_trapping_bodies)*
+                }
+            }
+
+            fn has_focus_at(&self, #path: &[iced_focus::PathSegment]) -> bool {
+                match self {
+                    #(#has_focus_at_bodies)*
+                }
+            }
+
+            fn focus_from(
+                &mut self,
+                #current: &[iced_focus::PathSegment],
+                #direction: iced_focus::Direction,
+            ) -> (iced_focus::State, Option<iced_focus::FocusPath>) {
+                #focus_from_method_body
+            }
+
+            fn sanitize(&mut self) {
+                #(#booleans)*
+
+                match self {
+                    #(#sanitize_bodies)*
+                }
+            }
         }
     };
     result.into()
 }
 
+/// Build the boolean expressions of the enable predicates of every variant of an enum.
+fn build_enum_booleans(variant_fields: &[Vec<FocusField>]) -> Vec<proc_macro2::TokenStream> {
+    // TODO: clean this up.
+    variant_fields
+        .iter()
+        .enumerate()
+        .map(|(index, fields)| {
+            let booleans = fields
+                .iter()
+                .map(|field| (field.index, &field.attribute))
+                .filter_map(|(field_index, attribute)| match attribute {
+                    FocusAttribute::Enable => None,
+                    FocusAttribute::EnableWith(_) => {
+                        Some(attribute.to_boolean_expression(field_index, Some(index)))
+                    }
+                });
+
+            quote! {
+                #(#booleans)*
+            }
+        })
+        .collect()
+}
+
+/// The match arms of an enum variant for each method of the `Focus` trait.
+#[derive(Debug)]
+struct VariantArms {
+    /// The arm of the `focus` method.
+    focus: proc_macro2::TokenStream,
+    /// The arm of the `has_focus` method.
+    has_focus: proc_macro2::TokenStream,
+    /// The arm of the `focus_path` method.
+    focus_path: proc_macro2::TokenStream,
+    /// The arm of the `focused_path` method.
+    focused_path: proc_macro2::TokenStream,
+    /// The arm of the `focused_paths` method.
+    focused_paths: proc_macro2::TokenStream,
+    /// The arm of the `disabled_focused_paths` method.
+    disabled_focused_paths: proc_macro2::TokenStream,
+    /// The arm of the `blur` method.
+    blur: proc_macro2::TokenStream,
+    /// The arm of the `is_trapping` method.
+    is_trapping: proc_macro2::TokenStream,
+    /// The arm of the `has_focus_at` method.
+    has_focus_at: proc_macro2::TokenStream,
+    /// The arm of the `focus_from` method.
+    focus_from: proc_macro2::TokenStream,
+    /// The arm of the `sanitize` method.
+    sanitize: proc_macro2::TokenStream,
+}
+
+/// Build the match arms of every variant of an enum.
+fn build_enum_arms<'a>(
+    container: &ContainerAttributes,
+    variants: impl IntoIterator<Item = &'a syn::Variant>,
+    variant_fields: &[Vec<FocusField>],
+) -> syn::Result<Vec<VariantArms>> {
+    variants
+        .into_iter()
+        .zip(variant_fields)
+        .enumerate()
+        .map(|(index, (variant, fields))| {
+            MemoryField::check_group(container, &variant.ident, &variant.fields, fields)?;
+
+            let memory = MemoryField::find_memory_field(&variant.fields);
+            Ok(impl_focus_enum_variant(
+                index,
+                container,
+                variant,
+                fields,
+                memory.as_ref(),
+            ))
+        })
+        .collect()
+}
+
 /// Implement the `Focus` trait for a variant of an enum.
 fn impl_focus_enum_variant(
     index: usize,
+    container: &ContainerAttributes,
     variant: &syn::Variant,
-) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    fields: &[FocusField],
+    memory: Option<&MemoryField>,
+) -> VariantArms {
     let ident = &variant.ident;
-    let array_name = quote! {fields};
+    let array_name = local("fields");
 
-    let (fields, len) = match variant.fields {
-        syn::Fields::Named(ref named) => {
-            (FocusField::collect_fields_named(named), named.named.len())
-        }
-        syn::Fields::Unnamed(ref unnamed) => (
-            FocusField::collect_fields_unnamed(unnamed),
-            unnamed.unnamed.len(),
-        ),
-        syn::Fields::Unit => (Vec::new(), 0),
-    };
-
-    let field_idents: Vec<proc_macro2::TokenStream> =
-        fields.iter().map(|field| field.ident(false)).collect();
-    let focus_method_body = build_focus_method_body(index, &array_name, &fields, len, false);
-    let has_focus_method_body = build_has_focus_method_body(&fields, false);
+    let field_bindings: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .map(|field| {
+            let member = field.ident(true);
+            let binding = field.ident(false);
+            quote! {#member: #binding}
+        })
+        .collect();
+    let focus_method_body =
+        build_focus_method_body(index, &array_name, container, fields, memory, false);
+    let has_focus_method_body = build_any_field_method_body(fields, "has_focus", false);
+    let focus_path_method_body = build_focus_path_method_body(index, &array_name, fields, false);
+    let focused_path_method_body = build_focused_path_method_body(fields, false);
+    let all_paths_method_body = build_focused_paths_method_body(fields, false);
+    let disabled_paths_method_body = build_disabled_focused_paths_method_body(fields, false);
+    let blur_method_body = build_blur_method_body(
+        index,
+        &array_name,
+        memory.filter(|_| container.group),
+        fields,
+        false,
+    );
+    let is_trapping_method_body = build_any_field_method_body(fields, "is_trapping", false);
+    let has_focus_at_method_body = build_has_focus_at_method_body(fields, false);
+    let focus_from_method_body = build_focus_from_method_body(index, &array_name, fields, false);
+    let sanitize_method_body = build_sanitize_method_body(index, fields, false);
 
     let variant_fields = match variant.fields {
-        syn::Fields::Named(_) => quote! { {#(#field_idents,)* ..} },
+        syn::Fields::Named(_) => quote! { {#(#field_bindings,)* ..} },
         syn::Fields::Unnamed(ref unnamed) => {
-            let idents = (0..unnamed.unnamed.len())
-                .into_iter()
-                .map(|i| syn::Ident::new(&format!("t_{}", i), proc_macro2::Span::call_site()));
+            let idents = (0..unnamed.unnamed.len()).map(field_binding);
             quote! { (#(#idents,)*) }
         }
         syn::Fields::Unit => quote! {},
     };
 
+    // Only the `focus` and the `blur` method need the memory of a group.
+    let focus_variant_fields = match (&variant.fields, memory) {
+        (syn::Fields::Named(_), Some(memory)) => {
+            let member = memory.ident(true);
+            let binding = memory.ident(false);
+            quote! { {#(#field_bindings,)* #member: #binding, ..} }
+        }
+        _ => variant_fields.clone(),
+    };
+
     let focus_method_body = quote! {
-        Self::#ident #variant_fields => {
+        Self::#ident #focus_variant_fields => {
             #focus_method_body
         }
     };
+    let blur_method_body = quote! {
+        Self::#ident #focus_variant_fields => {
+            #blur_method_body
+        }
+    };
 
-    let has_focus_method_body = quote! {
-        Self::#ident #variant_fields => {
-            #has_focus_method_body
+    // The arm of a method that binds the fields of the variant.
+    let arm = |body: proc_macro2::TokenStream| {
+        quote! {
+            Self::#ident #variant_fields => {
+                #body
+            }
         }
     };
 
-    (focus_method_body, has_focus_method_body)
+    VariantArms {
+        focus: focus_method_body,
+        has_focus: arm(has_focus_method_body),
+        focus_path: arm(focus_path_method_body),
+        focused_path: arm(focused_path_method_body),
+        focused_paths: arm(all_paths_method_body),
+        disabled_focused_paths: arm(disabled_paths_method_body),
+        blur: blur_method_body,
+        is_trapping: arm(is_trapping_method_body),
+        has_focus_at: arm(has_focus_at_method_body),
+        focus_from: arm(focus_from_method_body),
+        sanitize: arm(sanitize_method_body),
+    }
 }
 
 /// Build the `focus(&mut self, iced_focus::Direction) -> iced_focus::State` method of the `Focus` trait.
-fn build_focus_method_body<'a>(
+fn build_focus_method_body(
+    index: usize,
+    array_name: &syn::Ident,
+    container: &ContainerAttributes,
+    fields: &[FocusField],
+    memory: Option<&MemoryField>,
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let fields_array = build_fields_array(index, array_name, fields, with_self);
+    let direction = local("direction");
+
+    let focus_call = if container.group {
+        let memory = match memory {
+            Some(memory) if with_self => {
+                let ident = memory.ident(true);
+                quote! { &mut self.#ident }
+            }
+            Some(memory) => memory.ident(false),
+            // A group without focusable fields has nothing to remember.
+            None => quote! { &mut None },
+        };
+        let cycle = container.cycle;
+
+        quote! {
+            iced_focus::focus_group(&mut #array_name, #memory, #cycle, #direction)
+        }
+    } else if container.cycle {
+        let state = local("state");
+
+        quote! {
+            match #array_name.focus(#direction) {
+                iced_focus::State::Returned => #array_name.focus(#direction),
+                #state => #state,
+            }
+        }
+    } else {
+        quote! {
+            #array_name.focus(#direction)
+        }
+    };
+
+    quote! {
+        #fields_array
+
+        #focus_call
+    }
+}
+
+/// Build the `focus_path(&mut self, &[iced_focus::PathSegment]) -> iced_focus::State` method of the `Focus` trait.
+fn build_focus_path_method_body(
+    index: usize,
+    array_name: &syn::Ident,
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let fields_array = build_fields_array(index, array_name, fields, with_self);
+    let path = local("path");
+    let rest = local("rest");
+
+    let segment_arms = fields.iter().map(|field| {
+        let segment = &field.segment;
+        let slot = field.slot;
+        quote! {
+            Some((#segment, #rest)) => iced_focus::focus_path_at(&mut #array_name, #slot, #rest),
+        }
+    });
+
+    quote! {
+        #fields_array
+
+        match #path.split_first() {
+            None => #array_name.focus(iced_focus::Direction::First),
+            #(#segment_arms)*
+            Some(_) => iced_focus::State::Ignored,
+        }
+    }
+}
+
+/// Build the `has_focus_at(&self, &[iced_focus::PathSegment]) -> bool` method of the `Focus` trait.
+fn build_has_focus_at_method_body(
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let path = local("path");
+    let rest = local("rest");
+
+    let segment_arms = fields.iter().map(|field| {
+        let segment = &field.segment;
+        let boolean = match field.attribute {
+            FocusAttribute::Enable => quote! {},
+            FocusAttribute::EnableWith(ref predicate) => quote! {#predicate &&},
+        };
+        let call = field.call(with_self, &quote! {has_focus_at(#rest)}, &quote! {false});
+
+        quote! {
+            Some((#segment, #rest)) => #boolean #call,
+        }
+    });
+
+    quote! {
+        match #path.split_first() {
+            None => false,
+            #(#segment_arms)*
+            Some(_) => false,
+        }
+    }
+}
+
+/// Build the `focus_from(&mut self, &[iced_focus::PathSegment], iced_focus::Direction)` method of the `Focus` trait.
+fn build_focus_from_method_body(
+    index: usize,
+    array_name: &syn::Ident,
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let fields_array = build_fields_array(index, array_name, fields, with_self);
+    let field_segments = fields.iter().map(|field| &field.segment);
+    let segments = local("segments");
+    let current = local("current");
+    let direction = local("direction");
+
+    quote! {
+        #fields_array
+
+        let #segments = [#(#field_segments,)*];
+        iced_focus::focus_from_at(&mut #array_name, &#segments, #current, #direction)
+    }
+}
+
+/// Build the `focus_from` method of the `Focus` trait for an enum.
+fn build_enum_focus_from_method_body(
+    container: &ContainerAttributes,
+    booleans: &[proc_macro2::TokenStream],
+    arms: &[VariantArms],
+) -> proc_macro2::TokenStream {
+    if container.group || container.cycle {
+        return build_focus_from_fallback();
+    }
+
+    let focus_from_bodies = arms.iter().map(|arms| &arms.focus_from);
+
+    quote! {
+        #(#booleans)*
+
+        match self {
+            #(#focus_from_bodies)*
+        }
+    }
+}
+
+/// Build the `focus_from` method of the `Focus` trait for groups and cycles.
+///
+/// Both change the result of stepping out of the fields, so the focus is requested as usual.
+fn build_focus_from_fallback() -> proc_macro2::TokenStream {
+    let current = local("current");
+    let direction = local("direction");
+    let state = local("state");
+
+    quote! {
+        let _ = #current;
+        let #state = self.focus(#direction);
+        (#state, self.focused_path())
+    }
+}
+
+/// Build the `focused_path(&self) -> Option<iced_focus::FocusPath>` method of the `Focus` trait.
+fn build_focused_path_method_body(
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let path = local("path");
+
+    let lookups = fields.iter().map(|field| {
+        let segment = &field.segment;
+        let call = field.call(with_self, &quote! {focused_path()}, &quote! {None});
+        let lookup = quote! {
+            if let Some(mut #path) = #call {
+                #path.prepend(#segment);
+                return Some(#path);
+            }
+        };
+
+        match field.attribute {
+            FocusAttribute::Enable => lookup,
+            FocusAttribute::EnableWith(ref predicate) => quote! {
+                if #predicate {
+                    #lookup
+                }
+            },
+        }
+    });
+
+    quote! {
+        #(#lookups)*
+
+        None
+    }
+}
+
+/// Build the `focused_paths(&self) -> Vec<iced_focus::FocusPath>` method of the `Focus` trait.
+fn build_focused_paths_method_body(
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let paths = local("paths");
+    let path = local("path");
+
+    let lookups = fields.iter().map(|field| {
+        let segment = &field.segment;
+        let call = field.call(with_self, &quote! {focused_paths()}, &quote! {Vec::new()});
+        let lookup = quote! {
+            for mut #path in #call {
+                #path.prepend(#segment);
+                #paths.push(#path);
+            }
+        };
+
+        match field.attribute {
+            FocusAttribute::Enable => lookup,
+            FocusAttribute::EnableWith(ref predicate) => quote! {
+                if #predicate {
+                    #lookup
+                }
+            },
+        }
+    });
+
+    quote! {
+        #[allow(unused_mut)]
+        let mut #paths = Vec::new();
+
+        #(#lookups)*
+
+        #paths
+    }
+}
+
+/// Build the `disabled_focused_paths(&self) -> Vec<iced_focus::FocusPath>` method of the `Focus`
+/// trait.
+///
+/// Every focused element of a disabled field is hidden, the enabled fields are searched for
+/// disabled children.
+fn build_disabled_focused_paths_method_body(
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let paths = local("paths");
+    let path = local("path");
+
+    let lookups = fields.iter().map(|field| {
+        let segment = &field.segment;
+        let collect = |call: &proc_macro2::TokenStream| {
+            let call = field.call(with_self, call, &quote! {Vec::new()});
+            quote! {
+                for mut #path in #call {
+                    #path.prepend(#segment);
+                    #paths.push(#path);
+                }
+            }
+        };
+        let disabled = collect(&quote! {disabled_focused_paths()});
+
+        match field.attribute {
+            FocusAttribute::Enable => disabled,
+            FocusAttribute::EnableWith(ref predicate) => {
+                let focused = collect(&quote! {focused_paths()});
+
+                quote! {
+                    if !(#predicate) {
+                        #focused
+                    }
+                    #disabled
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[allow(unused_mut)]
+        let mut #paths = Vec::new();
+
+        #(#lookups)*
+
+        #paths
+    }
+}
+
+/// Build the `blur(&mut self)` method of the `Focus` trait.
+///
+/// Disabled fields are blurred as well, so that no hidden field keeps the focus. A group
+/// remembers the focused element first, like `iced_focus::FocusGroup`.
+fn build_blur_method_body(
+    index: usize,
+    array_name: &syn::Ident,
+    memory: Option<&MemoryField>,
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let calls = fields
+        .iter()
+        .map(|field| field.call_mut(with_self, &quote! {blur()}));
+
+    let remember = memory.map(|memory| {
+        let fields_array = build_fields_array(index, array_name, fields, with_self);
+        let path = local("path");
+        let target = if with_self {
+            let ident = memory.ident(true);
+            quote! { self.#ident }
+        } else {
+            let binding = memory.ident(false);
+            quote! { *#binding }
+        };
+
+        quote! {
+            {
+                #fields_array
+
+                if let Some(#path) = #array_name.focused_path() {
+                    #target = Some(#path);
+                }
+            }
+        }
+    });
+
+    quote! {
+        #remember
+
+        #(#calls)*
+    }
+}
+
+/// Build the `sanitize(&mut self)` method of the `Focus` trait.
+///
+/// Disabled fields are blurred, enabled fields are sanitized recursively.
+fn build_sanitize_method_body(
     index: usize,
-    array_name: &proc_macro2::TokenStream,
-    fields: &[FocusField<'a>],
-    len: usize,
+    fields: &[FocusField],
     with_self: bool,
 ) -> proc_macro2::TokenStream {
-    //let _capacity = fields.len();
+    let statements = fields.iter().map(|field| {
+        let sanitize = field.call_mut(with_self, &quote! {sanitize()});
+
+        match field.attribute {
+            FocusAttribute::Enable => sanitize,
+            FocusAttribute::EnableWith(ref predicate) => {
+                // The fields of an enum variant are borrowed, so the predicate is evaluated upfront.
+                let boolean = if with_self {
+                    quote! {#predicate}
+                } else {
+                    let boolean = boolean_ident(field.index, Some(index));
+                    quote! {#boolean}
+                };
+
+                let blur = field.call_mut(with_self, &quote! {blur()});
+
+                quote! {
+                    if #boolean {
+                        #sanitize
+                    } else {
+                        #blur
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        #(#statements)*
+    }
+}
+
+/// Build the array of the focusable fields that is passed to the implementation of the slice.
+fn build_fields_array(
+    index: usize,
+    array_name: &syn::Ident,
+    fields: &[FocusField],
+    with_self: bool,
+) -> proc_macro2::TokenStream {
+    let len = fields.len();
     let field_to_vector: Vec<proc_macro2::TokenStream> = fields
         .iter()
         .map(|field| {
@@ -263,134 +1061,332 @@ fn build_focus_method_body<'a>(
         #(#booleans)*
 
         #(#field_to_vector)*
-
-        #array_name.focus(direction)
     }
 }
 
-/// Build the `has_focus(&self) -> bool` method of the `Focus` trait.
-fn build_has_focus_method_body(
-    fields: &[FocusField<'_>],
+/// Build a `&self -> bool` method of the `Focus` trait, that is true if it is true for any enabled field.
+///
+/// Used for the `has_focus` and the `is_trapping` method.
+fn build_any_field_method_body(
+    fields: &[FocusField],
+    method: &str,
     with_self: bool,
 ) -> proc_macro2::TokenStream {
-    let field_idents: Vec<proc_macro2::TokenStream> =
-        fields.iter().map(|field| field.ident(with_self)).collect();
+    let method = syn::Ident::new(method, proc_macro2::Span::call_site());
 
-    let booleans: Vec<proc_macro2::TokenStream> = fields
-        .iter()
-        .map(|field| match field.attribute {
-            FocusAttribute::Enable(_) => quote! {},
-            FocusAttribute::EnableWith(_, ref path) => quote! {#path() &&},
-        })
-        .collect();
+    let calls = fields.iter().map(|field| {
+        let boolean = match field.attribute {
+            FocusAttribute::Enable => quote! {},
+            FocusAttribute::EnableWith(ref predicate) => quote! {#predicate &&},
+        };
+        let call = field.call(with_self, &quote! {#method()}, &quote! {false});
 
-    let with_self = if with_self {
-        quote! {self.}
-    } else {
-        quote! {}
-    };
+        quote! {#boolean #call}
+    });
 
     quote! {
-        #(#booleans #with_self#field_idents.has_focus() ||)* false
+        #(#calls ||)* false
     }
 }
 
-/// Extracts the idents of the annotated generics.
-fn generic_idents(generics: &syn::Generics) -> proc_macro2::TokenStream {
-    let generics: Vec<proc_macro2::TokenStream> = generics
-        .params
-        .iter()
-        .map(|param| match param {
-            syn::GenericParam::Type(ty) => {
-                let ident = &ty.ident;
-                quote! { #ident }
-            }
-            syn::GenericParam::Lifetime(lifetime) => {
-                let lifetime = &lifetime.lifetime;
-                quote! { #lifetime }
-            }
-            syn::GenericParam::Const(con) => {
-                let ident = &con.ident;
-                quote! { #ident }
+/// The `focus(...)` attributes annotated on the struct or enum itself.
+#[derive(Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct ContainerAttributes {
+    /// The `focus(cycle)` annotation.
+    ///
+    /// The focus wraps around at the ends of the fields and is never returned to the parent, so
+    /// a nested cycle confines the focus like a trap.
+    cycle: bool,
+    /// The `focus(group)` annotation.
+    ///
+    /// Combined with `focus(cycle)` the arrow directions wrap around at the ends of the group,
+    /// while `Tab` and `Shift+Tab` still leave the group.
+    group: bool,
+    /// The `focus(auto)` annotation.
+    auto: bool,
+    /// The `focus(fields)` annotation that generates the companion enum of the focusable fields.
+    fields: bool,
+    /// The additional predicates of the `focus(bound = "...")` annotation.
+    bounds: Vec<proc_macro2::TokenStream>,
+}
+
+impl ContainerAttributes {
+    /// Extract the [`ContainerAttributes`](ContainerAttributes) from the given slice of attributes.
+    fn extract_container_attributes(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("focus")) {
+            let meta = match attr.parse_meta()? {
+                syn::Meta::List(meta) => meta,
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "Expected the focus attribute to be of the form `focus(...)`.",
+                    ))
+                }
+            };
+
+            for nested in meta.nested {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident("cycle") => {
+                        container.cycle = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident("group") => {
+                        container.group = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident("auto") => {
+                        container.auto = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident("fields") => {
+                        container.fields = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("bound") => {
+                        match nv.lit {
+                            syn::Lit::Str(s) => {
+                                let bounds = s.parse_with(
+                                    syn::punctuated::Punctuated::<
+                                        syn::WherePredicate,
+                                        syn::Token![,],
+                                    >::parse_terminated,
+                                )?;
+                                container
+                                    .bounds
+                                    .extend(bounds.iter().map(|bound| quote! {#bound}));
+                            }
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "Expected the bounds of `focus(bound = \"...\")` to be a `str` literal.",
+                                ))
+                            }
+                        }
+                    }
+                    nested => {
+                        return Err(syn::Error::new_spanned(
+                            nested,
+                            "The focus attribute of a struct or enum must be `cycle`, `group`, `auto`, `fields` or `bound = \"...\"`.",
+                        ))
+                    }
+                }
             }
-        })
-        .collect();
+        }
 
-    if generics.is_empty() {
-        quote! {}
-    } else {
-        quote! { <#(#generics,)*> }
+        Ok(container)
+    }
+
+    /// Returns the given generics with the predicates of `focus(bound = "...")` added to the where clause.
+    fn add_bounds(&self, generics: &syn::Generics) -> syn::Generics {
+        let mut generics = generics.clone();
+
+        if !self.bounds.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(self.bounds.iter().map(|bound| -> syn::WherePredicate {
+                    syn::parse_quote! {#bound}
+                }));
+        }
+
+        generics
+    }
+}
+
+/// Represents the field annotated with `focus(memory)` that stores the last focused element of a group.
+#[derive(Debug)]
+struct MemoryField {
+    /// The ident of the field.
+    ident: proc_macro2::TokenStream,
+    /// The index of the field in the struct/enum.
+    index: usize,
+}
+
+impl MemoryField {
+    /// Returns the field annotated with `focus(memory)` if present.
+    fn find_memory_field(fields: &syn::Fields) -> Option<Self> {
+        fields
+            .iter()
+            .enumerate()
+            .find(|(_, field)| {
+                field.attrs.iter().any(|attr| {
+                    attr.path.is_ident("focus")
+                        && matches!(
+                            attr.parse_args::<syn::Path>(),
+                            Ok(path) if path.is_ident("memory")
+                        )
+                })
+            })
+            .map(|(index, field)| Self {
+                ident: field.ident.as_ref().map_or_else(
+                    || {
+                        let index = proc_macro2::Literal::usize_unsuffixed(index);
+                        quote! {#index}
+                    },
+                    |ident| quote! {#ident},
+                ),
+                index,
+            })
+    }
+
+    /// Checks that a group with focusable fields has a field annotated with `focus(memory)`.
+    ///
+    /// Without the memory the group could not re-enter the element that had the focus last.
+    fn check_group(
+        container: &ContainerAttributes,
+        ident: &syn::Ident,
+        fields: &syn::Fields,
+        focus_fields: &[FocusField],
+    ) -> syn::Result<()> {
+        if container.group && !focus_fields.is_empty() && Self::find_memory_field(fields).is_none()
+        {
+            Err(syn::Error::new_spanned(
+                ident,
+                "A focus group must have a field of type `Option<iced_focus::FocusPath>` annotated with `focus(memory)`.",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Return the ident of this field or the binding of the field inside of an enum variant.
+    fn ident(&self, with_self: bool) -> proc_macro2::TokenStream {
+        if with_self {
+            self.ident.clone()
+        } else {
+            let binding = field_binding(self.index);
+            quote! {#binding}
+        }
     }
 }
 
+/// The annotated position of a field in the focus chain and the span of its literal.
+type Order = (usize, proc_macro2::Span);
+
 /// Represents a field annotated with `focus(enable...)`.
 #[derive(Debug)]
-struct FocusField<'a> {
+struct FocusField {
     /// The ident of the field.
     ident: proc_macro2::TokenStream,
     /// The index of the field in the struct/enum.
     index: usize,
+    /// The index of the field in the array of focusable fields.
+    slot: usize,
+    /// The annotated position of the field in the focus chain and the span of its literal.
+    order: Option<Order>,
+    /// The pattern of the `iced_focus::PathSegment` addressing this field.
+    segment: proc_macro2::TokenStream,
     /// If the field is unnamed.
     unnamed: bool,
     /// The annotated focus attribute of the field.
-    attribute: FocusAttribute<'a>,
+    attribute: FocusAttribute,
+    /// If the field is included by `focus(auto)` and might not implement `Focus`.
+    auto: bool,
+    /// The span of the ident of the field, or of its type if the field is unnamed.
+    span: proc_macro2::Span,
 }
 
-impl<'a> FocusField<'a> {
-    /// Collect all fields annotated with `focus(enable...)` from a named fields list.
-    fn collect_fields_named(fields_named: &'a syn::FieldsNamed) -> Vec<Self> {
-        fields_named
-            .named
-            .iter()
-            .enumerate()
-            .filter_map(|(index, field)| FocusField::from_field_if_annotated(field, index))
-            .collect()
-    }
+impl FocusField {
+    /// Collect all fields annotated with `focus(enable...)` in the order of the focus chain.
+    ///
+    /// With `auto` every field without a focus attribute is collected as well.
+    ///
+    /// Fields annotated with an `order` come first in ascending order, followed by the remaining
+    /// fields in declaration order.
+    fn collect_fields(fields: &syn::Fields, auto: bool) -> syn::Result<Vec<Self>> {
+        let mut ordered: Vec<(usize, Self)> = Vec::new();
+        let mut unordered: Vec<Self> = Vec::new();
 
-    /// Collect all fields annotated with `focus(enable...)` from an unnamed fields list.
-    fn collect_fields_unnamed(fields_unnamed: &'a syn::FieldsUnnamed) -> Vec<Self> {
-        fields_unnamed
-            .unnamed
-            .iter()
+        for (index, field) in fields.iter().enumerate() {
+            let field = match FocusField::from_field_if_annotated(field, index, auto)? {
+                Some(field) => field,
+                None => continue,
+            };
+
+            match field.order {
+                Some((order, span)) if ordered.iter().any(|(other, _)| *other == order) => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!("The focus order {} is used by more than one field.", order),
+                    ));
+                }
+                Some((order, _)) => ordered.push((order, field)),
+                None => unordered.push(field),
+            }
+        }
+
+        ordered.sort_by_key(|(order, _)| *order);
+
+        Ok(ordered
+            .into_iter()
+            .map(|(_, field)| field)
+            .chain(unordered)
             .enumerate()
-            .filter_map(|(index, field)| FocusField::from_field_if_annotated(field, index))
-            .collect()
+            .map(|(slot, field)| Self { slot, ..field })
+            .collect())
     }
 
     /// Returns a [`FocusField`](FocusField) representation of the given field if the field was annotated with `focus(enable...)`.
-    fn from_field_if_annotated(field: &'a syn::Field, index: usize) -> Option<Self> {
-        let attribute = FocusAttribute::extract_focus_attribute(&field.attrs);
+    ///
+    /// With `auto` a field without a focus attribute is represented as well.
+    fn from_field_if_annotated(
+        field: &syn::Field,
+        index: usize,
+        auto: bool,
+    ) -> syn::Result<Option<Self>> {
+        let annotated = field.attrs.iter().any(|attr| attr.path.is_ident("focus"));
+        let attribute = if annotated {
+            FocusAttribute::extract_focus_attribute(&field.attrs)?
+        } else if auto {
+            Some((FocusAttribute::Enable, None))
+        } else {
+            None
+        };
         let index_literal = proc_macro2::Literal::usize_unsuffixed(index);
 
-        attribute.map(|attribute| Self {
+        Ok(attribute.map(|(attribute, order)| Self {
             ident: if let Some(ident) = field.ident.as_ref() {
                 quote! {#ident}
             } else {
                 quote! {#index_literal}
             },
             index,
+            segment: if let Some(ident) = field.ident.as_ref() {
+                let name = ident.unraw().to_string();
+                quote! {iced_focus::PathSegment::Name(#name)}
+            } else {
+                quote! {iced_focus::PathSegment::Index(#index_literal)}
+            },
+            slot: index,
+            order,
             unnamed: field.ident.is_none(),
             attribute,
-        })
+            auto: !annotated,
+            span: field
+                .ident
+                .as_ref()
+                .map_or_else(|| syn::spanned::Spanned::span(&field.ty), syn::Ident::span),
+        }))
     }
 
     /// TODO
-    fn add_struct_field_to_array(
-        &self,
-        array_name: &proc_macro2::TokenStream,
-    ) -> proc_macro2::TokenStream {
+    fn add_struct_field_to_array(&self, array_name: &syn::Ident) -> proc_macro2::TokenStream {
         let ident = self.ident(true);
-        let index = self.index;
+        let slot = self.slot;
         match self.attribute {
-            FocusAttribute::Enable(_) => quote! {
-                #array_name[#index] = Some(&mut self.#ident);
+            FocusAttribute::Enable if self.auto => {
+                let field = self.auto_mut(true);
+                quote! {
+                    #array_name[#slot] = #field;
+                }
+            }
+            FocusAttribute::Enable => quote! {
+                #array_name[#slot] = Some(&mut self.#ident);
             },
-            FocusAttribute::EnableWith(_, _) => {
-                let boolean =
-                    syn::Ident::new(&format!("b_{}", self.index), proc_macro2::Span::call_site());
+            FocusAttribute::EnableWith(_) => {
+                let boolean = boolean_ident(self.index, None);
                 quote! {
                     if #boolean {
-                        #array_name[#index] = Some(&mut self.#ident);
+                        #array_name[#slot] = Some(&mut self.#ident);
                     }
                 }
             }
@@ -401,130 +1397,363 @@ impl<'a> FocusField<'a> {
     fn add_enum_field_to_array(
         &self,
         index: usize,
-        array_name: &proc_macro2::TokenStream,
+        array_name: &syn::Ident,
     ) -> proc_macro2::TokenStream {
         let ident = self.ident(false);
-        let field_index = self.index;
+        let slot = self.slot;
 
         match self.attribute {
-            FocusAttribute::Enable(_) => quote! {
-                #array_name[#field_index] = Some(#ident);
+            FocusAttribute::Enable if self.auto => {
+                let field = self.auto_mut(false);
+                quote! {
+                    #array_name[#slot] = #field;
+                }
+            }
+            FocusAttribute::Enable => quote! {
+                #array_name[#slot] = Some(#ident);
             },
-            FocusAttribute::EnableWith(_, _) => {
-                let boolean = syn::Ident::new(
-                    &format!("b_{}_{}", index, self.index),
-                    proc_macro2::Span::call_site(),
-                );
+            FocusAttribute::EnableWith(_) => {
+                let boolean = boolean_ident(self.index, Some(index));
                 quote! {
                     if #boolean {
-                        #array_name[#field_index] = Some(#ident);
+                        #array_name[#slot] = Some(#ident);
                     }
                 }
             }
         }
     }
 
-    /// Return the ident of this field.
-    fn ident(&self, with_self: bool) -> proc_macro2::TokenStream {
-        if !with_self && self.unnamed {
-            // TODO: clean up
-            let tmp = syn::Ident::new(&format!("t_{}", self.ident), proc_macro2::Span::call_site());
-            quote! {#tmp}
+    /// Builds the call of a `&self` method of the `Focus` trait on this field.
+    ///
+    /// If the field was included by `focus(auto)` but is not focusable, the default is returned.
+    fn call(
+        &self,
+        with_self: bool,
+        call: &proc_macro2::TokenStream,
+        default: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let ident = self.ident(with_self);
+
+        if self.auto {
+            let field = if with_self {
+                quote! {&self.#ident}
+            } else {
+                quote! {&*#ident}
+            };
+            let element = local("element");
+
+            quote! {
+                {
+                    use iced_focus::auto::{FocusRef as _, NoFocusRef as _};
+                    (&iced_focus::auto::AutoRef(#field)).focus_ref()
+                }
+                .map_or(#default, |#element| #element.#call)
+            }
+        } else if with_self {
+            quote! {self.#ident.#call}
+        } else {
+            quote! {#ident.#call}
+        }
+    }
+
+    /// Builds the statement calling a `&mut self` method of the `Focus` trait on this field.
+    fn call_mut(
+        &self,
+        with_self: bool,
+        call: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let ident = self.ident(with_self);
+
+        if self.auto {
+            let field = self.auto_mut(with_self);
+            let element = local("element");
+            quote! {
+                if let Some(#element) = #field {
+                    #element.#call;
+                }
+            }
+        } else if with_self {
+            quote! {self.#ident.#call;}
+        } else {
+            quote! {#ident.#call;}
+        }
+    }
+
+    /// Builds the expression of type `Option<&mut dyn iced_focus::Focus>` of a field included by `focus(auto)`.
+    ///
+    /// It is `None`, if the type of the field does not implement `Focus`.
+    fn auto_mut(&self, with_self: bool) -> proc_macro2::TokenStream {
+        let ident = self.ident(with_self);
+        let field = if with_self {
+            quote! {&mut self.#ident}
         } else {
+            quote! {&mut *#ident}
+        };
+
+        quote! {
+            {
+                use iced_focus::auto::{FocusMut as _, NoFocusMut as _};
+                (&mut iced_focus::auto::AutoMut(Some(#field))).focus_mut()
+            }
+        }
+    }
+
+    /// Returns the name of this field in the companion enum of the focusable fields.
+    ///
+    /// Named fields are converted to camel case, unnamed fields are named after their index.
+    /// The fields of an enum variant are prefixed with the name of the variant.
+    fn companion_name(&self, variant: Option<&syn::Ident>) -> syn::Ident {
+        let field = if self.unnamed {
+            format!("Field{}", self.index)
+        } else {
+            self.ident
+                .to_string()
+                .trim_start_matches("r#")
+                .split('_')
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map_or_else(String::new, |first| {
+                        first.to_uppercase().chain(chars).collect()
+                    })
+                })
+                .collect()
+        };
+
+        let name = match variant {
+            Some(variant) => format!("{}{}", variant, field),
+            None => field,
+        };
+
+        syn::Ident::new(&name, proc_macro2::Span::call_site())
+    }
+
+    /// Return the ident of this field or the binding of the field inside of an enum variant.
+    fn ident(&self, with_self: bool) -> proc_macro2::TokenStream {
+        if with_self {
             self.ident.clone()
+        } else {
+            let binding = field_binding(self.index);
+            quote! {#binding}
         }
     }
 }
 
 /// The representation of the `focus(enable...)` attribute.
 #[derive(Debug)]
-enum FocusAttribute<'a> {
+enum FocusAttribute {
     /// The `focus(enable)` annotation.
-    Enable(&'a syn::Ident),
-    /// The `focus(enable = WITH)` annotation.
-    EnableWith(&'a syn::Ident, proc_macro2::TokenStream),
-    //Disable(&'a syn::Ident),
+    Enable,
+    /// The `focus(enable = "PATH")` or `focus(enable(EXPR))` annotation with the boolean expression of the predicate.
+    EnableWith(proc_macro2::TokenStream),
 }
 
-impl<'a> FocusAttribute<'a> {
-    /// Extract the [`FocusAttribute`](FocusAttribute) from the given slice of attributes if present.
-    fn extract_focus_attribute(attrs: &'a [syn::Attribute]) -> Option<Self> {
-        let attr: Option<(&syn::PathSegment, syn::MetaList)> = attrs
-            .iter()
-            .filter_map(|attr| {
-                match attr.parse_meta() {
-                    Ok(syn::Meta::List(meta)) => Some((&attr.path.segments, meta)),
-                    Ok(_) | Err(_) => None,
-                }
-            })
-            .find_map(|(path, meta)| {
-                path.iter()
-                    .find(|s| s.ident == "focus")
-                    .map(|path| (path, meta))
-            });
+impl FocusAttribute {
+    /// Extract the [`FocusAttribute`](FocusAttribute) and the optional `order` from the given slice of attributes if present.
+    ///
+    /// Every item of every `focus(...)` attribute is validated. Duplicate items and items that
+    /// conflict with `memory` or `skip` are rejected.
+    fn extract_focus_attribute(
+        attrs: &[syn::Attribute],
+    ) -> syn::Result<Option<(Self, Option<Order>)>> {
+        let mut first = None;
+        let mut attribute: Option<Self> = None;
+        let mut order: Option<Order> = None;
+        let mut excluded: Option<proc_macro2::Span> = None;
+        let mut chained = false;
 
-        attr.map(|(path, mut meta)| {
-            if meta.nested.len() != 1 {
-                panic!("Expected the focus attribute to be not empty.");
-            }
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("focus")) {
+            let _ = first.get_or_insert(attr);
 
-            match meta.nested.pop().unwrap().into_value() {
-                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => {
-                    let _ = nv
-                        .path
-                        .get_ident()
-                        .filter(|ident| *ident == "enable")
-                        .expect("Expected the ident `enable` inside the focus attribute.");
+            let items = attr.parse_args_with(FieldItem::parse_items)?;
 
-                    match nv.lit {
-                        syn::Lit::Str(s) => {
-                            let p: proc_macro2::TokenStream = syn::parse_str(&s.value()).unwrap();
+            if items.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Expected the focus attribute to be not empty.",
+                ));
+            }
 
-                            FocusAttribute::EnableWith(&path.ident, p)
-                        }
-                        _ => panic!(
-                            "Expected the path of `focus(enable = PATH) to be a `str` literal."
-                        ),
+            for (item, span) in items {
+                chained |= !matches!(item, FieldItem::Memory | FieldItem::Skip);
+
+                let duplicate = match item {
+                    FieldItem::Enable | FieldItem::EnableWith(_) if attribute.is_some() => {
+                        Some("enable")
                     }
-                }
-                syn::NestedMeta::Meta(syn::Meta::Path(p)) => {
-                    let _ = p
-                        .get_ident()
-                        .filter(|ident| *ident == "enable")
-                        .expect("Expected the ident `enable` inside the focus attribute.");
+                    FieldItem::Enable => {
+                        attribute = Some(FocusAttribute::Enable);
+                        None
+                    }
+                    FieldItem::EnableWith(predicate) => {
+                        attribute = Some(FocusAttribute::EnableWith(predicate));
+                        None
+                    }
+                    FieldItem::Order(..) if order.is_some() => Some("order"),
+                    FieldItem::Order(int, int_span) => {
+                        order = Some((int, int_span));
+                        None
+                    }
+                    FieldItem::Memory | FieldItem::Skip if excluded.is_some() => {
+                        Some("memory` or `skip")
+                    }
+                    FieldItem::Memory | FieldItem::Skip => {
+                        excluded = Some(span);
+                        None
+                    }
+                };
 
-                    FocusAttribute::Enable(&path.ident)
+                if let Some(name) = duplicate {
+                    return Err(syn::Error::new(
+                        span,
+                        format!("The `{}` item is used more than once on this field.", name),
+                    ));
                 }
-                _ => panic!(
-                    "The nested meta of the focus attribute must be `enable` or `enable = PATH`."
-                ),
             }
-        })
+        }
+
+        let first = match first {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        // The memory of a group and skipped fields are not part of the focus chain.
+        if let Some(excluded) = excluded {
+            return if chained {
+                Err(syn::Error::new(
+                    excluded,
+                    "Expected `memory` or `skip` to be the only item of the focus attributes of a field.",
+                ))
+            } else {
+                Ok(None)
+            };
+        }
+
+        let attribute = attribute.ok_or_else(|| {
+            syn::Error::new_spanned(
+                first,
+                "Expected `enable`, `enable = \"PATH\"` or `enable(EXPR)` inside the focus attribute.",
+            )
+        })?;
+
+        Ok(Some((attribute, order)))
     }
 
-    /// Builds the boolean expression if this [`FocusAttribute`](FocusAttribute) contains a path to a boolean method.
+    /// Builds the boolean expression if this [`FocusAttribute`](FocusAttribute) contains a predicate.
     fn to_boolean_expression(
         &self,
         field_index: usize,
         variant_index: Option<usize>,
     ) -> proc_macro2::TokenStream {
         match self {
-            FocusAttribute::Enable(_) => quote! {},
-            FocusAttribute::EnableWith(_, ref path) => {
-                let boolean = match variant_index {
-                    Some(variant_index) => syn::Ident::new(
-                        &format!("b_{}_{}", variant_index, field_index),
-                        proc_macro2::Span::call_site(),
-                    ),
-                    None => syn::Ident::new(
-                        &format!("b_{}", field_index),
-                        proc_macro2::Span::call_site(),
-                    ),
-                };
+            FocusAttribute::Enable => quote! {},
+            FocusAttribute::EnableWith(ref predicate) => {
+                let boolean = boolean_ident(field_index, variant_index);
                 quote! {
-                    let #boolean = #path();
+                    let #boolean = #predicate;
                 }
             }
         }
     }
 }
+
+/// A single item inside of the `focus(...)` attribute of a field.
+#[derive(Debug)]
+enum FieldItem {
+    /// The `enable` item.
+    Enable,
+    /// The `enable = "PATH"` or `enable(EXPR)` item with the boolean expression of the predicate.
+    EnableWith(proc_macro2::TokenStream),
+    /// The `order = N` item with the span of its literal.
+    Order(usize, proc_macro2::Span),
+    /// The `memory` item.
+    Memory,
+    /// The `skip` item.
+    Skip,
+}
+
+impl syn::parse::Parse for FieldItem {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+
+        if ident == "enable" {
+            if input.peek(syn::token::Paren) {
+                let content;
+                let _ = syn::parenthesized!(content in input);
+                let expr: syn::Expr = content.parse()?;
+
+                Ok(Self::EnableWith(quote! {(#expr)}))
+            } else if input.peek(syn::Token![=]) {
+                let _: syn::Token![=] = input.parse()?;
+
+                match input.parse()? {
+                    syn::Lit::Str(lit) => {
+                        let path = Self::parse_predicate(&lit)?;
+                        Ok(Self::EnableWith(quote! {#path()}))
+                    }
+                    lit => Err(syn::Error::new_spanned(
+                        lit,
+                        "Expected the path of `focus(enable = \"PATH\")` to be a `str` literal. Use `focus(enable(EXPR))` for expressions.",
+                    )),
+                }
+            } else {
+                Ok(Self::Enable)
+            }
+        } else if ident == "order" {
+            let _: syn::Token![=] = input.parse()?;
+
+            match input.parse()? {
+                syn::Lit::Int(int) => Ok(Self::Order(int.base10_parse()?, int.span())),
+                lit => Err(syn::Error::new_spanned(
+                    lit,
+                    "Expected the order of `focus(order = N)` to be an integer literal.",
+                )),
+            }
+        } else if ident == "memory" {
+            Ok(Self::Memory)
+        } else if ident == "skip" {
+            Ok(Self::Skip)
+        } else {
+            Err(syn::Error::new_spanned(
+                ident,
+                "The nested meta of the focus attribute must be `enable`, `enable = \"PATH\"`, `enable(EXPR)`, `order = N`, `memory` or `skip`.",
+            ))
+        }
+    }
+}
+
+impl FieldItem {
+    /// Parse the items of a `focus(...)` attribute together with the span of their first token.
+    fn parse_items(
+        input: syn::parse::ParseStream<'_>,
+    ) -> syn::Result<Vec<(Self, proc_macro2::Span)>> {
+        let items = syn::punctuated::Punctuated::<_, syn::Token![,]>::parse_terminated_with(
+            input,
+            |input| {
+                let span = input.span();
+                input.parse().map(|item: Self| (item, span))
+            },
+        )?;
+
+        Ok(items.into_iter().collect())
+    }
+
+    /// Parse the path to the predicate of `focus(enable = "PATH")`.
+    ///
+    /// The predicate is either a method of `self`, e.g. `self.is_enabled`, or a path to a function.
+    fn parse_predicate(lit: &syn::LitStr) -> syn::Result<proc_macro2::TokenStream> {
+        match lit.parse::<syn::Expr>()? {
+            syn::Expr::Field(ref field)
+                if matches!(field.member, syn::Member::Named(_))
+                    && matches!(*field.base, syn::Expr::Path(_) | syn::Expr::Field(_)) =>
+            {
+                Ok(quote! {#field})
+            }
+            syn::Expr::Path(ref path) => Ok(quote! {#path}),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "Expected the path of `focus(enable = \"PATH\")` to be a path to a method, e.g. `self.is_enabled`.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Detects the fields that implement [`Focus`](Focus) for `#[focus(auto)]`.
+//!
+//! The derive macro can't know the traits implemented by the type of a field. Instead it wraps
+//! the field and calls a method that is implemented twice: For the wrapper, if the field
+//! implements [`Focus`](Focus), and for a reference to the wrapper otherwise. The method
+//! resolution prefers the first one and only falls back to the autoref if its bound is not met.
+use crate::Focus;
+
+/// A shared reference to a field of unknown type.
+#[derive(Debug)]
+pub struct AutoRef<'a, T>(pub &'a T);
+
+/// A mutable reference to a field of unknown type.
+#[derive(Debug)]
+pub struct AutoMut<'a, T>(pub Option<&'a mut T>);
+
+/// Returns the shared reference to a field that implements [`Focus`](Focus).
+pub trait FocusRef<'a> {
+    /// Returns the field as a focusable element.
+    fn focus_ref(&self) -> Option<&'a dyn Focus>;
+}
+
+impl<'a, T: Focus> FocusRef<'a> for AutoRef<'a, T> {
+    fn focus_ref(&self) -> Option<&'a dyn Focus> {
+        Some(self.0)
+    }
+}
+
+/// Returns nothing for a field that does not implement [`Focus`](Focus).
+pub trait NoFocusRef<'a> {
+    /// Returns `None`, as the field is not focusable.
+    fn focus_ref(&self) -> Option<&'a dyn Focus>;
+}
+
+impl<'a, T> NoFocusRef<'a> for &AutoRef<'a, T> {
+    fn focus_ref(&self) -> Option<&'a dyn Focus> {
+        None
+    }
+}
+
+/// Returns the mutable reference to a field that implements [`Focus`](Focus).
+pub trait FocusMut<'a> {
+    /// Returns the field as a focusable element.
+    fn focus_mut(&mut self) -> Option<&'a mut dyn Focus>;
+}
+
+impl<'a, T: Focus> FocusMut<'a> for AutoMut<'a, T> {
+    fn focus_mut(&mut self) -> Option<&'a mut dyn Focus> {
+        self.0.take().map(|t| t as &mut dyn Focus)
+    }
+}
+
+/// Returns nothing for a field that does not implement [`Focus`](Focus).
+pub trait NoFocusMut<'a> {
+    /// Returns `None`, as the field is not focusable.
+    fn focus_mut(&mut self) -> Option<&'a mut dyn Focus>;
+}
+
+impl<'a, T> NoFocusMut<'a> for &mut AutoMut<'a, T> {
+    fn focus_mut(&mut self) -> Option<&'a mut dyn Focus> {
+        None
+    }
+}
@@ -0,0 +1,116 @@
+//! Report which element lost the focus and which one gained it.
+use iced_native::futures::future;
+use iced_native::Command;
+
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A change of the focus from one element to another.
+///
+/// Returned by the methods of [`TrackFocus`](TrackFocus), if the focus moved to another element,
+/// e.g. to validate a field when it loses the focus or to load data when it gains it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FocusChange {
+    /// The path to the element that had the focus before, if any.
+    pub from: Option<FocusPath>,
+    /// The path to the element that has the focus now, if any.
+    pub to: Option<FocusPath>,
+    /// The operation that changed the focus.
+    pub reason: Reason,
+}
+
+impl FocusChange {
+    /// Compares the paths to the focused element before and after an operation and returns the
+    /// [`FocusChange`](FocusChange), if they differ.
+    #[must_use]
+    pub fn between(from: Option<FocusPath>, to: Option<FocusPath>, reason: Reason) -> Option<Self> {
+        if from == to {
+            None
+        } else {
+            Some(Self { from, to, reason })
+        }
+    }
+
+    /// True, if the element at the given path lost the focus.
+    #[must_use]
+    pub fn lost(&self, path: &[PathSegment]) -> bool {
+        self.from.as_deref() == Some(path)
+    }
+
+    /// True, if the element at the given path gained the focus.
+    #[must_use]
+    pub fn gained(&self, path: &[PathSegment]) -> bool {
+        self.to.as_deref() == Some(path)
+    }
+}
+
+/// The operation that changed the focus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// A focus request into the given direction, e.g. by a key press.
+    Direction(Direction),
+    /// A focus request of the element at a path.
+    Path,
+    /// The focus was removed from every element.
+    Blur,
+    /// The focus was changed outside of this crate, e.g. by a click into a `TextInput` or next to
+    /// every input.
+    ///
+    /// See [`observe_focus`](crate::observe_focus).
+    External,
+}
+
+/// Focus operations that report the [`FocusChange`](FocusChange) they caused.
+///
+/// Implemented for every focusable element. Each method returns the result of the operation of
+/// the [`Focus`](Focus) trait and the change, if the focus moved to another element.
+pub trait TrackFocus: Focus {
+    /// Request a focus for the given direction and report the change.
+    fn focus_tracked(&mut self, direction: Direction) -> (State, Option<FocusChange>) {
+        track(self, Reason::Direction(direction), |element| {
+            element.focus(direction)
+        })
+    }
+
+    /// Request the focus of the element at the given path and report the change.
+    fn focus_path_tracked(&mut self, path: &[PathSegment]) -> (State, Option<FocusChange>) {
+        track(self, Reason::Path, |element| element.focus_path(path))
+    }
+
+    /// Remove the focus from every element and report the change.
+    fn blur_tracked(&mut self) -> Option<FocusChange> {
+        track(self, Reason::Blur, Focus::blur).1
+    }
+}
+
+impl<T: Focus + ?Sized> TrackFocus for T {}
+
+/// Applies the given operation to the focusable element and reports the change of the focus.
+///
+/// Use it for operations that are not covered by [`TrackFocus`](TrackFocus), e.g.
+/// [`Registry::focus`](crate::spatial::Registry::focus) or [`activate_trap`](crate::activate_trap).
+pub fn track<T, R, F>(element: &mut T, reason: Reason, f: F) -> (R, Option<FocusChange>)
+where
+    T: Focus + ?Sized,
+    F: FnOnce(&mut T) -> R,
+{
+    let from = element.focused_path();
+    let result = f(element);
+    let to = element.focused_path();
+
+    (result, FocusChange::between(from, to, reason))
+}
+
+/// Returns a [`Command`](Command) that produces a message of the given change, if any.
+///
+/// Return it from the `update` method of your application to handle the change as a message.
+pub fn command<Message>(
+    change: Option<FocusChange>,
+    f: fn(FocusChange) -> Message,
+) -> Command<Message>
+where
+    Message: 'static,
+{
+    change.map_or_else(Command::none, |change| {
+        Command::perform(future::ready(change), f)
+    })
+}
@@ -0,0 +1,211 @@
+//! Check that at most one element of the focus chain has the focus.
+use std::fmt;
+
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A violation of the invariant that at most one enabled element has the focus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation {
+    /// Several elements have the focus at once, e.g. after a click while a focused input was not
+    /// part of the view. See [`observe_focus`](crate::observe_focus).
+    SeveralFocused(Vec<FocusPath>),
+    /// The element has the focus, but is hidden from the focus chain because it or one of its
+    /// parents is disabled. See [`Focus::sanitize`](Focus::sanitize).
+    Disabled(FocusPath),
+    /// The path was expected to lead to the focused element, but does not, e.g. because it leads
+    /// into an `Option` that is `None` now.
+    Stale(FocusPath),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SeveralFocused(paths) => {
+                write!(f, "several elements have the focus:")?;
+                for path in paths {
+                    write!(f, " `{}`", path)?;
+                }
+                Ok(())
+            }
+            Self::Disabled(path) => write!(f, "the disabled element `{}` has the focus", path),
+            Self::Stale(path) => write!(f, "the path `{}` leads to no focused element", path),
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+/// Walks the given focusable element and returns every violation of the invariant that at most
+/// one enabled element has the focus.
+///
+/// Call [`Focus::sanitize`](Focus::sanitize) to remove the focus from disabled elements and
+/// [`observe_focus`](crate::observe_focus) to resolve several focused elements.
+pub fn diagnose<T>(element: &T) -> Vec<Violation>
+where
+    T: Focus + ?Sized,
+{
+    let mut violations = Vec::new();
+
+    let focused = element.focused_paths();
+    if focused.len() > 1 {
+        violations.push(Violation::SeveralFocused(focused));
+    }
+
+    violations.extend(
+        element
+            .disabled_focused_paths()
+            .into_iter()
+            .map(Violation::Disabled),
+    );
+
+    violations
+}
+
+/// Checks that the given path, e.g. a path remembered by your application, leads to the element
+/// that has the focus.
+///
+/// Returns [`Violation::Stale`](Violation::Stale), if the element lost the focus or does not exist
+/// anymore.
+pub fn diagnose_path<T>(element: &T, path: &[PathSegment]) -> Option<Violation>
+where
+    T: Focus + ?Sized,
+{
+    if element.has_focus_at(path) {
+        None
+    } else {
+        Some(Violation::Stale(path.iter().copied().collect()))
+    }
+}
+
+/// Walks the given focusable element and returns the violations of the invariant that at most
+/// one enabled element has the focus as an error.
+///
+/// See [`diagnose`](diagnose).
+///
+/// # Errors
+///
+/// Returns every [`Violation`](Violation) that was found.
+pub fn validate<T>(element: &T) -> Result<(), Vec<Violation>>
+where
+    T: Focus + ?Sized,
+{
+    let violations = diagnose(element);
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// A wrapper that checks the invariant that at most one enabled element has the focus after
+/// every focus request on the wrapped element.
+///
+/// Panics with the [`Violation`](Violation)s if the check fails. The check only runs with debug
+/// assertions, so the wrapper has no cost in release builds.
+#[derive(Clone, Debug, Default)]
+pub struct FocusChecker<T> {
+    /// The wrapped focusable element.
+    inner: T,
+}
+
+impl<T> FocusChecker<T> {
+    /// Creates a new [`FocusChecker`](FocusChecker) around the given focusable element.
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consumes the [`FocusChecker`](FocusChecker) and returns the wrapped element.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Focus> FocusChecker<T> {
+    /// Panics with the violations of the invariant after the given operation, if debug assertions
+    /// are enabled.
+    fn check(&self, operation: &str) {
+        debug_assert!(
+            validate(&self.inner).is_ok(),
+            "The focus is invalid after `{}`: {}",
+            operation,
+            diagnose(&self.inner)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+impl<T> std::ops::Deref for FocusChecker<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusChecker<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for FocusChecker<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        let state = self.inner.focus(direction);
+        self.check("focus");
+        state
+    }
+
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        let state = self.inner.focus_path(path);
+        self.check("focus_path");
+        state
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.inner.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.inner.blur();
+        self.check("blur");
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        let result = self.inner.focus_from(current, direction);
+        self.check("focus_from");
+        result
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
+}
@@ -0,0 +1,282 @@
+//! Draw a focus ring around the content of a focused element.
+use iced_graphics::{Backend, Primitive};
+use iced_native::event::{self, Event};
+use iced_native::{
+    layout, overlay, Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
+};
+
+/// A container that draws a focus ring around its content while the element has the focus.
+///
+/// The ring is drawn inside of the padding of the container, so that it does not overlap the
+/// content. By default the padding is the thickness of the ring.
+#[allow(missing_debug_implementations)]
+pub struct FocusIndicator<'a, Message, Renderer: self::Renderer> {
+    /// If the element has the focus.
+    focused: bool,
+    /// The appearance of the focus ring.
+    style: Style,
+    /// The space between the content and the border of the container.
+    padding: Option<u16>,
+    /// The wrapped content.
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> FocusIndicator<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`FocusIndicator`](FocusIndicator) around the given content.
+    ///
+    /// The ring is drawn, if `focused` is true. Query it with
+    /// [`Focus::has_focus`](crate::Focus::has_focus) before the state of the element is borrowed
+    /// by its widget.
+    pub fn new<T>(focused: bool, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            focused,
+            style: Style::default(),
+            padding: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the color of the focus ring.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn color(mut self, color: Color) -> Self {
+        self.style.color = color;
+        self
+    }
+
+    /// Sets the thickness of the focus ring.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn width(mut self, width: f32) -> Self {
+        self.style.width = width;
+        self
+    }
+
+    /// Sets the radius of the corners of the focus ring.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.style.radius = radius;
+        self
+    }
+
+    /// Sets the [`Style`](Style) of the focus ring.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the space between the content and the border of the container.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = Some(units);
+        self
+    }
+
+    /// Returns the space between the content and the border of the container.
+    fn padding_units(&self) -> f32 {
+        self.padding
+            .map_or_else(|| self.style.width.ceil(), f32::from)
+    }
+}
+
+/// The appearance of a focus ring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    /// The color of the ring.
+    pub color: Color,
+    /// The thickness of the ring.
+    pub width: f32,
+    /// The radius of the corners of the ring.
+    pub radius: f32,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.2, 0.5, 1.0),
+            width: 2.0,
+            radius: 0.0,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for FocusIndicator<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let padding = self.padding_units();
+
+        let limits = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .pad(padding);
+
+        let mut content = self.content.layout(renderer, &limits);
+        content.move_to(Point::new(padding, padding));
+        let size = limits.resolve(content.size()).pad(padding);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        layout
+            .children()
+            .next()
+            .map_or(event::Status::Ignored, |content_layout| {
+                self.content.on_event(
+                    event,
+                    content_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let content_layout = layout.children().next().unwrap_or(layout);
+        let ring = if self.focused {
+            Some(&self.style)
+        } else {
+            None
+        };
+
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            viewport,
+            ring,
+            &self.content,
+            content_layout,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        /// Distinguishes this widget from the other widgets.
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.padding_units().to_bits().hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let content_layout = layout.children().next()?;
+        self.content.overlay(content_layout)
+    }
+}
+
+/// The renderer of a [`FocusIndicator`](FocusIndicator).
+pub trait Renderer: iced_native::Renderer {
+    /// Draws the content and the focus ring, if any, around it.
+    #[allow(clippy::too_many_arguments)]
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        ring: Option<&Style>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<B: Backend> Renderer for iced_graphics::Renderer<B> {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        ring: Option<&Style>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) =
+            content.draw(self, defaults, content_layout, cursor_position, viewport);
+
+        match ring {
+            Some(style) => (
+                Primitive::Group {
+                    primitives: vec![
+                        content,
+                        Primitive::Quad {
+                            bounds,
+                            background: Background::Color(Color::TRANSPARENT),
+                            border_radius: style.radius,
+                            border_width: style.width,
+                            border_color: style.color,
+                        },
+                    ],
+                },
+                mouse_interaction,
+            ),
+            None => (content, mouse_interaction),
+        }
+    }
+}
+
+impl Renderer for iced_native::renderer::Null {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        _ring: Option<&Style>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        content.draw(self, defaults, content_layout, cursor_position, viewport);
+    }
+}
+
+impl<'a, Message, Renderer> From<FocusIndicator<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(indicator: FocusIndicator<'a, Message, Renderer>) -> Self {
+        Element::new(indicator)
+    }
+}
@@ -0,0 +1,310 @@
+//! Grids of elements that are navigated by rows and columns.
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// The behavior of the arrow directions at the edges of a [`FocusGrid`](FocusGrid).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wrap {
+    /// The focus stays at the edge and the request is ignored.
+    Stop,
+    /// The focus wraps around to the other end of the same row or column.
+    Around,
+    /// The focus continues at the other end of the next row or column, like text flowing from one
+    /// line to the next. The request is ignored at the first and the last element of the grid.
+    Continue,
+}
+
+// `#[default]` on enum variants requires Rust 1.62.
+#[allow(clippy::derivable_impls)]
+impl Default for Wrap {
+    fn default() -> Self {
+        Self::Stop
+    }
+}
+
+/// A grid of focusable elements that knows its rows and columns.
+///
+/// [`Direction::Forwards`](Direction::Forwards) and [`Direction::Backwards`](Direction::Backwards)
+/// traverse the grid row by row. The arrow directions move the focus between the rows and the
+/// columns, [`Direction::RowStart`](Direction::RowStart) and [`Direction::RowEnd`](Direction::RowEnd)
+/// move it to the first and the last element of the row. Elements that ignore the request are
+/// skipped.
+///
+/// The rows may have different lengths. Moving up or down into a shorter row focuses its last
+/// element. The elements are addressed by the index of their row followed by the index of their
+/// column, just like the elements of a `Vec<Vec<T>>`.
+#[derive(Clone, Debug, Default)]
+pub struct FocusGrid<T> {
+    /// The rows of the wrapped focusable elements.
+    rows: Vec<Vec<T>>,
+    /// The behavior of the arrow directions at the edges of the grid.
+    wrap: Wrap,
+}
+
+impl<T> FocusGrid<T> {
+    /// Creates a new [`FocusGrid`](FocusGrid) of the given rows.
+    #[must_use]
+    pub const fn new(rows: Vec<Vec<T>>) -> Self {
+        Self {
+            rows,
+            wrap: Wrap::Stop,
+        }
+    }
+
+    /// Creates a new [`FocusGrid`](FocusGrid) that splits the given elements into rows of the
+    /// given number of columns.
+    ///
+    /// The last row is shorter, if the number of elements is not a multiple of the columns.
+    #[must_use]
+    pub fn with_columns(elements: Vec<T>, columns: usize) -> Self {
+        let columns = columns.max(1);
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for (index, element) in elements.into_iter().enumerate() {
+            if index % columns == 0 {
+                rows.push(Vec::with_capacity(columns));
+            }
+            if let Some(row) = rows.last_mut() {
+                row.push(element);
+            }
+        }
+
+        Self::new(rows)
+    }
+
+    /// Sets the behavior of the arrow directions at the edges of the [`FocusGrid`](FocusGrid).
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Returns the element at the given row and column.
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.rows.get(row).and_then(|r| r.get(column))
+    }
+
+    /// Returns the element at the given row and column mutably.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.rows.get_mut(row).and_then(|r| r.get_mut(column))
+    }
+
+    /// Consumes the [`FocusGrid`](FocusGrid) and returns the wrapped rows.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> Vec<Vec<T>> {
+        self.rows
+    }
+
+    /// Returns the positions visited by an arrow direction from the given position, in order.
+    fn walk(&self, row: usize, column: usize, direction: Direction) -> Vec<(usize, usize)> {
+        let horizontal = matches!(direction, Direction::Left | Direction::Right);
+        let forwards = matches!(direction, Direction::Right | Direction::Down);
+
+        // The elements of the row or the column of the focused element. A shorter row is
+        // represented by its last element.
+        let line: Vec<(usize, usize)> = if horizontal {
+            (0..self.rows[row].len()).map(|c| (row, c)).collect()
+        } else {
+            self.rows
+                .iter()
+                .enumerate()
+                .filter(|(_, elements)| !elements.is_empty())
+                .map(|(r, elements)| (r, column.min(elements.len() - 1)))
+                .collect()
+        };
+        let index = line
+            .iter()
+            .position(|&(r, c)| r == row && (c == column || !horizontal))
+            .unwrap_or(0);
+        let (before, after) = (&line[..index], &line[(index + 1).min(line.len())..]);
+
+        let mut positions: Vec<(usize, usize)> = if forwards {
+            after.to_vec()
+        } else {
+            before.iter().rev().copied().collect()
+        };
+
+        match self.wrap {
+            Wrap::Stop => {}
+            Wrap::Around if forwards => positions.extend(before),
+            Wrap::Around => positions.extend(after.iter().rev()),
+            Wrap::Continue => {
+                // The elements of the k-th row or column.
+                let cells = |k: usize| -> Vec<(usize, usize)> {
+                    if horizontal {
+                        (0..self.rows[k].len()).map(|c| (k, c)).collect()
+                    } else {
+                        (0..self.rows.len())
+                            .filter(|r| k < self.rows[*r].len())
+                            .map(|r| (r, k))
+                            .collect()
+                    }
+                };
+                let (current, count) = if horizontal {
+                    (row, self.rows.len())
+                } else {
+                    (column, self.rows.iter().map(Vec::len).max().unwrap_or(0))
+                };
+
+                if forwards {
+                    positions.extend((current + 1..count).flat_map(cells));
+                } else {
+                    positions.extend((0..current).rev().flat_map(|k| cells(k).into_iter().rev()));
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Request the focus of the element at the given row and column and remove the focus from
+    /// every other element.
+    fn focus_cell(&mut self, row: usize, column: usize) -> State
+    where
+        T: Focus,
+    {
+        self.rows
+            .focus_path(&[PathSegment::Index(row), PathSegment::Index(column)])
+    }
+}
+
+impl<T: Focus> FocusGrid<T> {
+    /// Returns the row and the column of the element that has the focus, if any.
+    #[must_use]
+    pub fn focused_cell(&self) -> Option<(usize, usize)> {
+        self.rows.iter().enumerate().find_map(|(row, elements)| {
+            elements
+                .iter()
+                .position(Focus::has_focus)
+                .map(|column| (row, column))
+        })
+    }
+}
+
+impl<T> std::ops::Deref for FocusGrid<T> {
+    type Target = Vec<Vec<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rows
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusGrid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rows
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for FocusGrid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<T, const C: usize, const R: usize> From<[[T; C]; R]> for FocusGrid<T> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Self::new(
+            IntoIterator::into_iter(rows)
+                .map(|row| IntoIterator::into_iter(row).collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T: Focus> Focus for FocusGrid<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        // An active trap inside of the grid confines the focus to itself.
+        if self.rows.is_trapping() {
+            return self.rows.focus(direction);
+        }
+
+        let (row, column) = match direction {
+            Direction::Forwards | Direction::Backwards | Direction::First | Direction::Last => {
+                return self.rows.focus(direction)
+            }
+            _ => match self.focused_cell() {
+                Some(cell) => cell,
+                None => return State::Ignored,
+            },
+        };
+
+        // A nested grid or group handles the direction first.
+        match self.rows[row][column].focus(direction) {
+            State::Ignored => {}
+            state => return state,
+        }
+
+        // Moving to the start or the end of the row keeps the focus, if it is already there.
+        let positions: Vec<(usize, usize)> = match direction {
+            Direction::RowStart => (0..=column).map(|c| (row, c)).collect(),
+            Direction::RowEnd => (column..self.rows[row].len())
+                .rev()
+                .map(|c| (row, c))
+                .collect(),
+            _ => self.walk(row, column, direction),
+        };
+
+        if positions
+            .into_iter()
+            .any(|(r, c)| self.focus_cell(r, c) == State::Kept)
+        {
+            State::Kept
+        } else {
+            State::Ignored
+        }
+    }
+
+    fn has_focus(&self) -> bool {
+        self.rows.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.rows.focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.rows.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.rows.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.rows.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.rows.blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.rows.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.rows.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        match direction {
+            Direction::Forwards | Direction::Backwards | Direction::First | Direction::Last => {
+                self.rows.focus_from(current, direction)
+            }
+            _ => {
+                let state = self.focus(direction);
+                (state, self.focused_path())
+            }
+        }
+    }
+
+    fn sanitize(&mut self) {
+        self.rows.sanitize();
+    }
+}
@@ -0,0 +1,179 @@
+//! Groups of elements that form a single stop of the focus chain.
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A group of focusable elements that forms a single stop of the focus chain.
+///
+/// [`Direction::Forwards`](Direction::Forwards) and [`Direction::Backwards`](Direction::Backwards)
+/// enter and leave the group as a unit, while the arrow directions move the focus between the
+/// elements inside of the group. When the group is entered again, the element that had the
+/// focus last will be focused.
+#[derive(Clone, Debug, Default)]
+pub struct FocusGroup<T> {
+    /// The wrapped focusable elements.
+    inner: T,
+    /// The path to the element that had the focus when the group was left.
+    last: Option<FocusPath>,
+    /// If the arrow directions wrap around at the ends of the group.
+    cycle: bool,
+}
+
+impl<T> FocusGroup<T> {
+    /// Creates a new [`FocusGroup`](FocusGroup) around the given focusable elements.
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            last: None,
+            cycle: false,
+        }
+    }
+
+    /// Sets whether the arrow directions wrap around at the ends of the [`FocusGroup`](FocusGroup).
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn cycle(mut self, cycle: bool) -> Self {
+        self.cycle = cycle;
+        self
+    }
+
+    /// Consumes the [`FocusGroup`](FocusGroup) and returns the wrapped elements.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> std::ops::Deref for FocusGroup<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusGroup<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for FocusGroup<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        focus_group(&mut self.inner, &mut self.last, self.cycle, direction)
+    }
+
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.inner.focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.inner.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        if let Some(path) = self.inner.focused_path() {
+            self.last = Some(path);
+        }
+        self.inner.blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
+}
+
+/// Request a focus for the given direction on the elements of a group.
+///
+/// `last` stores the path to the element that had the focus when the group was left.
+#[doc(hidden)]
+pub fn focus_group<T: Focus + ?Sized>(
+    inner: &mut T,
+    last: &mut Option<FocusPath>,
+    cycle: bool,
+    direction: Direction,
+) -> State {
+    // An active trap inside of the group confines the focus to itself.
+    if inner.is_trapping() {
+        return inner.focus(direction);
+    }
+
+    match direction {
+        Direction::Forwards | Direction::Backwards => {
+            if inner.has_focus() {
+                *last = inner.focused_path();
+                inner.blur();
+                return State::Returned;
+            }
+
+            let state = last
+                .as_ref()
+                .map_or(State::Ignored, |path| inner.focus_path(path));
+
+            if state == State::Kept {
+                state
+            } else if direction == Direction::Forwards {
+                inner.focus(Direction::First)
+            } else {
+                inner.focus(Direction::Last)
+            }
+        }
+        Direction::First | Direction::Last => inner.focus(direction),
+        Direction::Up | Direction::Down | Direction::Left | Direction::Right => {
+            if !inner.has_focus() {
+                return State::Ignored;
+            }
+
+            // A nested group handles the arrow directions first.
+            match inner.focus(direction) {
+                State::Ignored => step(inner, cycle, direction),
+                state => state,
+            }
+        }
+        Direction::RowStart | Direction::RowEnd => {
+            if !inner.has_focus() {
+                return State::Ignored;
+            }
+
+            // The elements of a group form a single row.
+            match inner.focus(direction) {
+                State::Ignored if direction == Direction::RowStart => inner.focus(Direction::First),
+                State::Ignored => inner.focus(Direction::Last),
+                state => state,
+            }
+        }
+    }
+}
+
+/// Moves the focus inside of a group one step into the given arrow direction.
+fn step<T: Focus + ?Sized>(inner: &mut T, cycle: bool, direction: Direction) -> State {
+    let (next, wrap, stop) = match direction {
+        Direction::Down | Direction::Right => {
+            (Direction::Forwards, Direction::First, Direction::Last)
+        }
+        _ => (Direction::Backwards, Direction::Last, Direction::First),
+    };
+
+    match inner.focus(next) {
+        State::Returned => inner.focus(if cycle { wrap } else { stop }),
+        state => state,
+    }
+}
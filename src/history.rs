@@ -0,0 +1,208 @@
+//! A history of the focused elements to restore an earlier focus.
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// The default number of paths remembered by a [`FocusHistory`](FocusHistory).
+const DEFAULT_LIMIT: usize = 32;
+
+/// A wrapper around the root of a focus chain that remembers the previously focused elements.
+///
+/// Every successful focus request that moves the focus to another element records the path
+/// to the element that had the focus before. [`restore_previous`](FocusHistory::restore_previous)
+/// moves the focus back to it, e.g. after a popup was closed or a list was rebuilt.
+#[derive(Clone, Debug)]
+pub struct FocusHistory<T> {
+    /// The wrapped focusable element.
+    inner: T,
+    /// The paths to the previously focused elements, the most recent one last.
+    history: Vec<FocusPath>,
+    /// The maximum number of remembered paths.
+    limit: usize,
+}
+
+impl<T> FocusHistory<T> {
+    /// Creates a new [`FocusHistory`](FocusHistory) around the given focusable element.
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            history: Vec::new(),
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// Sets the maximum number of paths remembered by the [`FocusHistory`](FocusHistory).
+    ///
+    /// If the limit is exceeded, the oldest paths are forgotten first.
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self.truncate();
+        self
+    }
+
+    /// Returns the remembered paths, the most recent one last.
+    pub fn history(&self) -> &[FocusPath] {
+        &self.history
+    }
+
+    /// Forgets all remembered paths.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Removes the most recent path from the history and returns it.
+    pub fn pop(&mut self) -> Option<FocusPath> {
+        self.history.pop()
+    }
+
+    /// Consumes the [`FocusHistory`](FocusHistory) and returns the wrapped element.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Adds the given path to the history, unless it is already the most recent one.
+    fn record(&mut self, path: FocusPath) {
+        if self.history.last() != Some(&path) {
+            self.history.push(path);
+            self.truncate();
+        }
+    }
+
+    /// Forgets the oldest paths that exceed the limit.
+    fn truncate(&mut self) {
+        if self.history.len() > self.limit {
+            let _ = self.history.drain(..self.history.len() - self.limit);
+        }
+    }
+}
+
+impl<T: Focus> FocusHistory<T> {
+    /// Adds the path to the element that currently has the focus to the history.
+    ///
+    /// Returns false, if no element has the focus.
+    pub fn push(&mut self) -> bool {
+        let path = self.inner.focused_path();
+        let pushed = path.is_some();
+
+        if let Some(path) = path {
+            self.record(path);
+        }
+
+        pushed
+    }
+
+    /// Moves the focus back to the most recent element of the history.
+    ///
+    /// Paths to elements that do not exist anymore or are disabled are skipped. If no element
+    /// of the history can be focused, the focus is left untouched and
+    /// [`State::Ignored`](State::Ignored) is returned. Restoring does not add the current
+    /// element to the history.
+    pub fn restore_previous(&mut self) -> State {
+        let current = self.inner.focused_path();
+
+        while let Some(path) = self.history.pop() {
+            if Some(&path) == current.as_ref() {
+                continue;
+            }
+
+            if self.inner.focus_path(&path) == State::Kept {
+                return State::Kept;
+            }
+        }
+
+        State::Ignored
+    }
+
+    /// Records the previously focused path, if the given request moved the focus.
+    fn track(&mut self, request: impl FnOnce(&mut T) -> State) -> State {
+        let previous = self.inner.focused_path();
+        let state = request(&mut self.inner);
+
+        if let Some(previous) = previous {
+            if self.inner.focused_path().as_ref() != Some(&previous) {
+                self.record(previous);
+            }
+        }
+
+        state
+    }
+}
+
+impl<T: Default> Default for FocusHistory<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> std::ops::Deref for FocusHistory<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusHistory<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for FocusHistory<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        self.track(|inner| inner.focus(direction))
+    }
+
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.track(|inner| inner.focus_path(path))
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.inner.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        if let Some(path) = self.inner.focused_path() {
+            self.record(path);
+        }
+        self.inner.blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        let (state, path) = self.inner.focus_from(current, direction);
+
+        if path.as_deref() != Some(current) {
+            self.record(current.to_vec().into());
+        }
+
+        (state, path)
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
+}
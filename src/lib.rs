@@ -53,12 +53,125 @@ extern crate iced_focus_derive;
 #[doc(hidden)]
 pub use iced_focus_derive::*;
 
+#[doc(hidden)]
+pub mod auto;
+
+pub mod change;
+pub use change::{FocusChange, Reason, TrackFocus};
+
+pub mod diagnose;
+pub use diagnose::{FocusChecker, Violation};
+
+pub mod focus_indicator;
+pub use focus_indicator::FocusIndicator;
+
+mod grid;
+pub use grid::{FocusGrid, Wrap};
+
+mod group;
+#[doc(hidden)]
+pub use group::focus_group;
+pub use group::FocusGroup;
+
+mod history;
+pub use history::FocusHistory;
+
+mod manager;
+pub use manager::FocusManager;
+
+mod observer;
+pub use observer::{observe_focus, snapshot_focus, FocusObserver};
+
+mod path;
+pub use path::{FocusPath, PathSegment};
+
+pub mod spatial;
+
+pub mod subscription;
+
+pub mod widget;
+pub use widget::Focusable;
+
+mod trap;
+pub use trap::{activate_trap, release_trap, FocusTrap};
+
 /// This trait specifies an element in the applications state that can be added to the focus chain.
 pub trait Focus {
     /// Request a focus for the given direction.    
     fn focus(&mut self, direction: Direction) -> State;
     /// True, if this element has the focus.
     fn has_focus(&self) -> bool;
+    /// Remove the focus from this element and all of its children.
+    ///
+    /// A focus request can't remove the focus from every element, e.g. from a leaf that toggles
+    /// its focus for any direction, so every element has to implement it.
+    fn blur(&mut self);
+    /// Request the focus of the element at the given path and remove the focus from every other element.
+    ///
+    /// An empty path requests the focus of the first element. If the path does not lead to a
+    /// focusable element, the focus is left untouched and [`State::Ignored`](State::Ignored) is returned.
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        if path.is_empty() {
+            self.focus(Direction::First)
+        } else {
+            State::Ignored
+        }
+    }
+    /// Returns the path to the element that has the focus, if any.
+    fn focused_path(&self) -> Option<FocusPath> {
+        if self.has_focus() {
+            Some(FocusPath::new())
+        } else {
+            None
+        }
+    }
+    /// Returns the paths to every element that has the focus.
+    ///
+    /// Usually at most one element has the focus. But a click only removes the focus from the
+    /// inputs of the view, so an input that is not shown, e.g. on a hidden page, keeps its focus
+    /// while the clicked one gains it. See [`FocusObserver`](FocusObserver).
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.focused_path().into_iter().collect()
+    }
+    /// Returns the paths to every element that has the focus, but is hidden from the focus chain
+    /// because it or one of its parents is disabled.
+    ///
+    /// These elements are skipped by [`focused_paths`](Focus::focused_paths), see
+    /// [`diagnose`](diagnose::diagnose).
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        Vec::new()
+    }
+    /// True, if this element or one of its children is an active [`FocusTrap`](FocusTrap).
+    ///
+    /// While a trap is active, the focus requests are confined to the trap.
+    fn is_trapping(&self) -> bool {
+        false
+    }
+    /// True, if the element at the given path has the focus.
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.focused_path()
+            .map_or(false, |focused| *focused == *path)
+    }
+    /// Request a focus for the given direction, starting at the element at the given path.
+    ///
+    /// `current` must be the path to the element that has the focus. Unlike [`focus`](Focus::focus),
+    /// the element that has the focus is not searched for. Returns the state of the request and the
+    /// path to the element that has the focus afterwards.
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        let _ = current;
+        let state = self.focus(direction);
+        (state, self.focused_path())
+    }
+    /// Remove the focus from every child that is disabled, but still has the focus.
+    ///
+    /// A disabled element is not part of the focus chain, so its focus would be hidden from
+    /// [`has_focus`](Focus::has_focus). The derived [`focus`](Focus::focus) method sanitizes the
+    /// element before every request.
+    fn sanitize(&mut self) {}
 }
 
 /// The state returned by the focus request on a focusable element.
@@ -73,19 +186,32 @@ pub enum State {
 }
 
 impl Focus for iced::text_input::State {
-    fn focus(&mut self, _direction: Direction) -> State {
-        if self.is_focused() {
-            self.unfocus();
-            State::Returned
-        } else {
-            self.focus();
-            State::Kept
+    fn focus(&mut self, direction: Direction) -> State {
+        match direction {
+            Direction::Forwards | Direction::Backwards if self.is_focused() => {
+                self.unfocus();
+                State::Returned
+            }
+            Direction::Forwards | Direction::Backwards | Direction::First | Direction::Last => {
+                self.focus();
+                State::Kept
+            }
+            Direction::Up
+            | Direction::Down
+            | Direction::Left
+            | Direction::Right
+            | Direction::RowStart
+            | Direction::RowEnd => State::Ignored,
         }
     }
 
     fn has_focus(&self) -> bool {
         self.is_focused()
     }
+
+    fn blur(&mut self) {
+        self.unfocus();
+    }
 }
 
 impl<T> Focus for Vec<T>
@@ -99,6 +225,46 @@ where
     fn has_focus(&self) -> bool {
         self.iter().any(|t| t.has_focus())
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.as_mut_slice().focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.as_slice().focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.as_slice().focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.as_slice().disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.as_mut_slice().blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.as_slice().is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.as_slice().has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self.as_mut_slice().focus_from(current, direction)
+    }
+
+    fn sanitize(&mut self) {
+        self.as_mut_slice().sanitize();
+    }
 }
 
 /// TODO: Change to `as_mut_slice` in the future.
@@ -114,6 +280,46 @@ where
     fn has_focus(&self) -> bool {
         self[..].has_focus()
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self[..].focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self[..].focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self[..].focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self[..].disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self[..].blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self[..].is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self[..].has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self[..].focus_from(current, direction)
+    }
+
+    fn sanitize(&mut self) {
+        self[..].sanitize();
+    }
 }
 
 impl<T: Focus> Focus for Box<T> {
@@ -124,6 +330,46 @@ impl<T: Focus> Focus for Box<T> {
     fn has_focus(&self) -> bool {
         self.as_ref().has_focus()
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.as_mut().focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.as_ref().focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.as_mut().blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.as_ref().is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.as_ref().has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self.as_mut().focus_from(current, direction)
+    }
+
+    fn sanitize(&mut self) {
+        self.as_mut().sanitize();
+    }
 }
 
 /// Ugly workaround.
@@ -136,6 +382,46 @@ impl Focus for Box<&mut dyn Focus> {
     fn has_focus(&self) -> bool {
         self.as_ref().has_focus()
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.as_mut().focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.as_ref().focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.as_mut().blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.as_ref().is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.as_ref().has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self.as_mut().focus_from(current, direction)
+    }
+
+    fn sanitize(&mut self) {
+        self.as_mut().sanitize();
+    }
 }
 
 impl<T: Focus> Focus for [T] {
@@ -144,67 +430,263 @@ impl<T: Focus> Focus for [T] {
             return State::Ignored;
         }
 
-        if let Some((index, _)) = self.iter().enumerate().find(|(_i, e)| e.has_focus()) {
-            let state = self
-                .get_mut(index)
-                .map_or(State::Ignored, |element| element.focus(direction));
+        // An active trap confines the focus to itself.
+        if let Some(trap) = self.iter_mut().find(|e| e.is_trapping()) {
+            return trap.focus(direction);
+        }
+
+        let current = self.iter().position(|e| e.has_focus());
 
-            if state != State::Returned {
-                return state;
+        match direction {
+            Direction::Forwards | Direction::Backwards => step(self, current, direction),
+            Direction::First | Direction::Last => jump(self, current, direction),
+            Direction::Up
+            | Direction::Down
+            | Direction::Left
+            | Direction::Right
+            | Direction::RowStart
+            | Direction::RowEnd => {
+                current.map_or(State::Ignored, |index| self[index].focus(direction))
             }
+        }
+    }
 
-            let is_at_bound = match direction {
-                Direction::Forwards => index == self.len() - 1,
-                Direction::Backwards => index == 0,
-            };
+    fn has_focus(&self) -> bool {
+        self.iter().any(|t| t.has_focus())
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        match path.split_first() {
+            None => self.focus(Direction::First),
+            Some((&PathSegment::Index(index), rest)) => focus_path_at(self, index, rest),
+            Some(_) => State::Ignored,
+        }
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.iter().enumerate().find_map(|(index, e)| {
+            e.focused_path().map(|mut path| {
+                path.prepend(PathSegment::Index(index));
+                path
+            })
+        })
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, e)| {
+                e.focused_paths().into_iter().map(move |mut path| {
+                    path.prepend(PathSegment::Index(index));
+                    path
+                })
+            })
+            .collect()
+    }
 
-            if is_at_bound {
-                return State::Returned;
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, e)| {
+                e.disabled_focused_paths().into_iter().map(move |mut path| {
+                    path.prepend(PathSegment::Index(index));
+                    path
+                })
+            })
+            .collect()
+    }
+
+    fn blur(&mut self) {
+        self.iter_mut().for_each(Focus::blur);
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.iter().any(|t| t.is_trapping())
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        match path.split_first() {
+            Some((&PathSegment::Index(index), rest)) => {
+                self.get(index).map_or(false, |e| e.has_focus_at(rest))
             }
+            _ => false,
+        }
+    }
 
-            match direction {
-                Direction::Forwards => {
-                    self[index + 1..]
-                        .iter_mut()
-                        .find_map(|e| match e.focus(direction) {
-                            State::Kept => Some(State::Kept),
-                            State::Returned | State::Ignored => None,
-                        })
-                }
-                Direction::Backwards => {
-                    self[..index]
-                        .iter_mut()
-                        .rev()
-                        .find_map(|e| match e.focus(direction) {
-                            State::Kept => Some(State::Kept),
-                            State::Returned | State::Ignored => None,
-                        })
-                }
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        let current = match current.split_first() {
+            Some((&PathSegment::Index(index), rest)) => Some((index, rest)),
+            _ => None,
+        };
+
+        step_from(self, current, direction, PathSegment::Index)
+    }
+
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(Focus::sanitize);
+    }
+}
+
+/// Request the focus of the element at the given path inside the element at the given index.
+///
+/// If the element keeps the focus, the focus is removed from all of its siblings.
+/// If one of its siblings is an active trap, the request is ignored.
+#[doc(hidden)]
+pub fn focus_path_at<T: Focus>(elements: &mut [T], index: usize, path: &[PathSegment]) -> State {
+    let trapped = elements
+        .iter()
+        .enumerate()
+        .any(|(i, e)| i != index && e.is_trapping());
+
+    if trapped {
+        return State::Ignored;
+    }
+
+    let state = elements
+        .get_mut(index)
+        .map_or(State::Ignored, |element| element.focus_path(path));
+
+    if state == State::Kept {
+        elements
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, e)| *i != index && e.has_focus())
+            .for_each(|(_, e)| e.blur());
+    }
+
+    state
+}
+
+/// Moves the focus of the given elements one step into the given direction.
+///
+/// `current` is the index of the element that currently has the focus.
+fn step<T: Focus>(elements: &mut [T], current: Option<usize>, direction: Direction) -> State {
+    let (remaining, fallback) = match current {
+        Some(index) => {
+            let state = elements[index].focus(direction);
+
+            if state != State::Returned {
+                return state;
             }
-            .unwrap_or(State::Returned)
-        } else {
-            // TODO: Clean up
-            match direction {
-                Direction::Forwards => self[..].iter_mut().find_map(|e| match e.focus(direction) {
-                    State::Kept => Some(State::Kept),
-                    State::Returned | State::Ignored => None,
-                }),
-                Direction::Backwards => {
-                    self[..]
-                        .iter_mut()
-                        .rev()
-                        .find_map(|e| match e.focus(direction) {
-                            State::Kept => Some(State::Kept),
-                            State::Returned | State::Ignored => None,
-                        })
-                }
+
+            if direction == Direction::Backwards {
+                (0..index, State::Returned)
+            } else {
+                (index + 1..elements.len(), State::Returned)
             }
-            .unwrap_or(State::Ignored)
         }
+        None => (0..elements.len(), State::Ignored),
+    };
+
+    let kept = |e: &mut T| e.focus(direction) == State::Kept;
+
+    let found = if direction == Direction::Backwards {
+        elements[remaining].iter_mut().rev().any(kept)
+    } else {
+        elements[remaining].iter_mut().any(kept)
+    };
+
+    if found {
+        State::Kept
+    } else {
+        fallback
     }
+}
 
-    fn has_focus(&self) -> bool {
-        self.iter().any(|t| t.has_focus())
+/// Moves the focus of the given elements to the first or last focusable element.
+///
+/// `current` is the index of the element that currently has the focus.
+fn jump<T: Focus>(elements: &mut [T], current: Option<usize>, direction: Direction) -> State {
+    let kept = |e: &mut T| e.focus(direction) == State::Kept;
+
+    let target = if direction == Direction::Last {
+        elements.iter_mut().rposition(kept)
+    } else {
+        elements.iter_mut().position(kept)
+    };
+
+    match (current, target) {
+        (Some(current), Some(target)) if current != target => elements[current].blur(),
+        _ => {}
+    }
+
+    target.map_or(State::Ignored, |_| State::Kept)
+}
+
+/// Request the focus for the given direction on the elements, starting at the element at the given path.
+///
+/// `segments` are the path segments addressing the elements.
+#[doc(hidden)]
+pub fn focus_from_at<T: Focus>(
+    elements: &mut [T],
+    segments: &[PathSegment],
+    current: &[PathSegment],
+    direction: Direction,
+) -> (State, Option<FocusPath>) {
+    let current = current.split_first().and_then(|(first, rest)| {
+        segments
+            .iter()
+            .position(|segment| segment == first)
+            .map(|index| (index, rest))
+    });
+
+    step_from(elements, current, direction, |index| segments[index])
+}
+
+/// Moves the focus of the given elements into the given direction without searching for the focused element.
+///
+/// `current` is the index of the element that has the focus and the path inside of it. `segment`
+/// returns the path segment addressing the element at an index. Only the steps forwards and
+/// backwards are accelerated, all other requests are passed to the slice.
+fn step_from<T: Focus>(
+    elements: &mut [T],
+    current: Option<(usize, &[PathSegment])>,
+    direction: Direction,
+    segment: impl Fn(usize) -> PathSegment,
+) -> (State, Option<FocusPath>) {
+    let prepend = |index: usize, path: Option<FocusPath>| {
+        path.map(|mut path| {
+            path.prepend(segment(index));
+            path
+        })
+    };
+
+    match current {
+        Some((index, rest))
+            if index < elements.len()
+                && (direction == Direction::Forwards || direction == Direction::Backwards) =>
+        {
+            let (state, path) = elements[index].focus_from(rest, direction);
+
+            if state != State::Returned {
+                return (state, prepend(index, path));
+            }
+
+            let next = if direction == Direction::Backwards {
+                (0..index)
+                    .rev()
+                    .find(|i| elements[*i].focus(direction) == State::Kept)
+            } else {
+                (index + 1..elements.len()).find(|i| elements[*i].focus(direction) == State::Kept)
+            };
+
+            next.map_or((State::Returned, None), |next| {
+                (State::Kept, prepend(next, elements[next].focused_path()))
+            })
+        }
+        _ => {
+            let state = elements.focus(direction);
+            let path = elements
+                .iter()
+                .enumerate()
+                .find_map(|(index, e)| prepend(index, e.focused_path()));
+
+            (state, path)
+        }
     }
 }
 
@@ -216,6 +698,52 @@ impl<T: Focus> Focus for Option<T> {
     fn has_focus(&self) -> bool {
         self.as_ref().map_or(false, |t| t.has_focus())
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.as_mut().map_or(State::Ignored, |t| t.focus_path(path))
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.as_ref().and_then(|t| t.focused_path())
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().map_or_else(Vec::new, |t| t.focused_paths())
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref()
+            .map_or_else(Vec::new, |t| t.disabled_focused_paths())
+    }
+
+    fn blur(&mut self) {
+        if let Some(t) = self.as_mut() {
+            t.blur();
+        }
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.as_ref().map_or(false, |t| t.is_trapping())
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.as_ref().map_or(false, |t| t.has_focus_at(path))
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self.as_mut()
+            .map_or((State::Ignored, None), |t| t.focus_from(current, direction))
+    }
+
+    fn sanitize(&mut self) {
+        if let Some(t) = self.as_mut() {
+            t.sanitize();
+        }
+    }
 }
 
 /// Ugly workaround.
@@ -228,13 +756,190 @@ impl Focus for Option<&mut dyn Focus> {
     fn has_focus(&self) -> bool {
         self.as_ref().map_or(false, |t| t.has_focus())
     }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.as_mut().map_or(State::Ignored, |t| t.focus_path(path))
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.as_ref().and_then(|t| t.focused_path())
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref().map_or_else(Vec::new, |t| t.focused_paths())
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.as_ref()
+            .map_or_else(Vec::new, |t| t.disabled_focused_paths())
+    }
+
+    fn blur(&mut self) {
+        if let Some(t) = self.as_mut() {
+            t.blur();
+        }
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.as_ref().map_or(false, |t| t.is_trapping())
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.as_ref().map_or(false, |t| t.has_focus_at(path))
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        self.as_mut()
+            .map_or((State::Ignored, None), |t| t.focus_from(current, direction))
+    }
+
+    fn sanitize(&mut self) {
+        if let Some(t) = self.as_mut() {
+            t.sanitize();
+        }
+    }
+}
+
+/// A wrapper around the root of a focus chain that cycles the focus.
+///
+/// When the wrapped element returns the focus at the end of the chain, the focus
+/// wraps around to the first element (or to the last element if the direction
+/// is [`Direction::Backwards`](Direction::Backwards)) instead of being dropped.
+///
+/// The focus is never returned to the parent of the [`Cycle`](Cycle). Nested inside of another
+/// focusable element it confines the focus to the wrapped element, just like an active
+/// [`FocusTrap`](FocusTrap).
+#[derive(Clone, Debug, Default)]
+pub struct Cycle<T> {
+    /// The wrapped focusable element.
+    inner: T,
+}
+
+impl<T> Cycle<T> {
+    /// Creates a new [`Cycle`](Cycle) around the given focusable element.
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consumes the [`Cycle`](Cycle) and returns the wrapped element.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> std::ops::Deref for Cycle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for Cycle<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for Cycle<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        match self.inner.focus(direction) {
+            State::Returned => self.inner.focus(direction),
+            state => state,
+        }
+    }
+
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.inner.focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.inner.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.inner.blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        match self.inner.focus_from(current, direction) {
+            (State::Returned, _) => {
+                let state = self.inner.focus(direction);
+                (state, self.inner.focused_path())
+            }
+            result => result,
+        }
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
 }
 
 /// The direction of the focus request.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     /// Request a forward focus on the focus chain.
     Forwards,
     /// Request a backward focus on the focus chain.
     Backwards,
+    /// Request the focus of the first element of the focus chain.
+    First,
+    /// Request the focus of the last element of the focus chain.
+    Last,
+    /// Request to move the focus up.
+    ///
+    /// Moves the focus to the previous element of the surrounding [`FocusGroup`](FocusGroup).
+    Up,
+    /// Request to move the focus down.
+    ///
+    /// Moves the focus to the next element of the surrounding [`FocusGroup`](FocusGroup).
+    Down,
+    /// Request to move the focus to the left.
+    ///
+    /// Moves the focus to the previous element of the surrounding [`FocusGroup`](FocusGroup).
+    Left,
+    /// Request to move the focus to the right.
+    ///
+    /// Moves the focus to the next element of the surrounding [`FocusGroup`](FocusGroup).
+    Right,
+    /// Request to move the focus to the start of the row.
+    ///
+    /// Moves the focus to the first element of the row of the surrounding [`FocusGrid`](FocusGrid)
+    /// or to the first element of the surrounding [`FocusGroup`](FocusGroup).
+    RowStart,
+    /// Request to move the focus to the end of the row.
+    ///
+    /// Moves the focus to the last element of the row of the surrounding [`FocusGrid`](FocusGrid)
+    /// or to the last element of the surrounding [`FocusGroup`](FocusGroup).
+    RowEnd,
 }
@@ -0,0 +1,153 @@
+//! A manager that caches the position of the focus in the focus chain.
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A wrapper around the root of a focus chain that caches the path to the focused element.
+///
+/// A plain focus request searches for the focused element by asking every element on every level
+/// of the focus chain if it has the focus. The [`FocusManager`](FocusManager) remembers the path to
+/// the focused element instead and only visits the elements along this path and the ones that are
+/// skipped to reach the next element.
+///
+/// Before each request the cached path is checked. If the element lost its focus, e.g. because the
+/// user clicked into another input or the element was disabled, the wrapped element is
+/// [sanitized](Focus::sanitize) and the path is searched again. Call [`resync`](FocusManager::resync)
+/// after changing the focus of the wrapped element by other means while the cached element keeps
+/// its focus.
+#[derive(Clone, Debug, Default)]
+pub struct FocusManager<T> {
+    /// The wrapped focusable element.
+    inner: T,
+    /// The cached path to the element that has the focus.
+    current: Option<FocusPath>,
+}
+
+impl<T> FocusManager<T> {
+    /// Creates a new [`FocusManager`](FocusManager) around the given focusable element.
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            current: None,
+        }
+    }
+
+    /// Returns the cached path to the element that has the focus.
+    ///
+    /// The path might be outdated, if the focus was changed by other means than the manager.
+    pub const fn current(&self) -> Option<&FocusPath> {
+        self.current.as_ref()
+    }
+
+    /// Consumes the [`FocusManager`](FocusManager) and returns the wrapped element.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Focus> FocusManager<T> {
+    /// Searches the path to the element that has the focus and caches it.
+    pub fn resync(&mut self) {
+        self.current = self.inner.focused_path();
+    }
+
+    /// True, if the cached path leads to the element that has the focus.
+    fn is_synced(&self) -> bool {
+        self.current
+            .as_ref()
+            .map_or(false, |path| self.inner.has_focus_at(path))
+    }
+}
+
+impl<T> std::ops::Deref for FocusManager<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusManager<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for FocusManager<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        if !self.is_synced() {
+            self.inner.sanitize();
+            self.resync();
+        }
+
+        if let Some(current) = self.current.take() {
+            let (state, path) = self.inner.focus_from(&current, direction);
+            self.current = path;
+            return state;
+        }
+
+        let state = self.inner.focus(direction);
+        self.current = self.inner.focused_path();
+        state
+    }
+
+    fn has_focus(&self) -> bool {
+        self.is_synced() || self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        let state = self.inner.focus_path(path);
+
+        if state == State::Kept {
+            if self.inner.has_focus_at(path) {
+                self.current = Some(path.to_vec().into());
+            } else {
+                self.resync();
+            }
+        }
+
+        state
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        if self.is_synced() {
+            self.current.clone()
+        } else {
+            self.inner.focused_path()
+        }
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.inner.blur();
+        self.current = None;
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        let (state, path) = self.inner.focus_from(current, direction);
+        self.current.clone_from(&path);
+        (state, path)
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
+}
@@ -0,0 +1,81 @@
+//! Detect the focus changes made outside of this crate, e.g. by a mouse click.
+use crate::{Focus, FocusChange, FocusPath, Reason, State};
+
+/// Remembers the element that had the focus to detect the changes made outside of this crate.
+///
+/// iced moves the focus to a `TextInput` when it is clicked and removes it from the other inputs
+/// of the view, but your application is not told about it. Call [`observe_focus`](observe_focus)
+/// at the start of `update` to report the change, e.g. to update the state that depends on the
+/// focused element or to [resync](crate::FocusManager::resync) a [`FocusManager`](crate::FocusManager).
+/// Call [`snapshot_focus`](snapshot_focus) at the end of `update` to remember the focus after
+/// your own focus requests.
+///
+/// Inputs that are not part of the view, e.g. on a hidden page, don't see the click and keep
+/// their focus. The observer removes it, so that only the newly focused element keeps the focus.
+#[derive(Clone, Debug, Default)]
+pub struct FocusObserver {
+    /// The path to the element that had the focus at the last observation.
+    last: Option<FocusPath>,
+}
+
+impl FocusObserver {
+    /// Creates a new [`FocusObserver`](FocusObserver).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Returns the path to the element that had the focus at the last observation.
+    #[must_use]
+    pub const fn last(&self) -> Option<&FocusPath> {
+        self.last.as_ref()
+    }
+}
+
+/// Detects the focus changes made outside of this crate since the last observation.
+///
+/// Disabled elements lose their focus first. If several elements have the focus, the newly
+/// focused one keeps it and the focus is removed from the others. If the new element can't be
+/// focused, e.g. because it is outside of an active [`FocusTrap`](crate::FocusTrap), the focus
+/// goes back to the element that had it before. Returns the change with [`Reason::External`](Reason::External), if the focus moved.
+pub fn observe_focus<S, F>(root: &mut S, mut observer: F) -> Option<FocusChange>
+where
+    S: Focus + ?Sized,
+    F: FnMut(&mut S) -> &mut FocusObserver,
+{
+    let last = observer(root).last.take();
+    root.sanitize();
+
+    let focused = root.focused_paths();
+
+    if focused.len() > 1 {
+        // A newly focused element wins over the one that had the focus before.
+        let target = focused
+            .iter()
+            .find(|path| Some(*path) != last.as_ref())
+            .unwrap_or(&focused[0]);
+
+        if root.focus_path(target) != State::Kept {
+            root.blur();
+
+            if let Some(last) = last.as_ref() {
+                let _ = root.focus_path(last);
+            }
+        }
+    }
+
+    let to = root.focused_path();
+    observer(root).last.clone_from(&to);
+
+    FocusChange::between(last, to, Reason::External)
+}
+
+/// Remembers the element that has the focus, e.g. after the focus requests of your application.
+pub fn snapshot_focus<S, F>(root: &mut S, observer: F)
+where
+    S: Focus + ?Sized,
+    F: FnOnce(&mut S) -> &mut FocusObserver,
+{
+    let focused = root.focused_path();
+    observer(root).last = focused;
+}
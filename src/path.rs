@@ -0,0 +1,111 @@
+//! Paths to the elements of a focus chain.
+use std::fmt;
+
+/// A single step of a [`FocusPath`](FocusPath).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// The index of an element in a collection or of an unnamed field.
+    Index(usize),
+    /// The name of a named field.
+    Name(&'static str),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "{}", index),
+            Self::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The path to an element of the focus chain.
+///
+/// Each segment selects a child of the current element: an index into a `Vec`,
+/// an array or a tuple struct, or the name of a field of a derived struct or
+/// enum variant. `Option` and `Box` are transparent and do not add a segment.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FocusPath {
+    /// The segments of the path, starting at the root.
+    segments: Vec<PathSegment>,
+}
+
+impl FocusPath {
+    /// Creates a new empty [`FocusPath`](FocusPath) pointing to the root.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Appends an index to the [`FocusPath`](FocusPath).
+    #[must_use]
+    pub fn index(mut self, index: usize) -> Self {
+        self.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Appends the name of a field to the [`FocusPath`](FocusPath).
+    #[must_use]
+    pub fn name(mut self, name: &'static str) -> Self {
+        self.push(PathSegment::Name(name));
+        self
+    }
+
+    /// Appends the given segment to the [`FocusPath`](FocusPath).
+    pub fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    /// Inserts the given segment at the start of the [`FocusPath`](FocusPath).
+    pub fn prepend(&mut self, segment: PathSegment) {
+        self.segments.insert(0, segment);
+    }
+
+    /// Returns the segments of the [`FocusPath`](FocusPath).
+    #[must_use]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl std::ops::Deref for FocusPath {
+    type Target = [PathSegment];
+
+    fn deref(&self) -> &Self::Target {
+        &self.segments
+    }
+}
+
+impl std::borrow::Borrow<[PathSegment]> for FocusPath {
+    fn borrow(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl From<Vec<PathSegment>> for FocusPath {
+    fn from(segments: Vec<PathSegment>) -> Self {
+        Self { segments }
+    }
+}
+
+impl std::iter::FromIterator<PathSegment> for FocusPath {
+    fn from_iter<I: IntoIterator<Item = PathSegment>>(iter: I) -> Self {
+        Self {
+            segments: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for FocusPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
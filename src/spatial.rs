@@ -0,0 +1,361 @@
+//! Move the focus to the geometrically nearest element with the arrow keys.
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use iced_native::event::{self, Event};
+use iced_native::{
+    layout, overlay, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A registry of the bounds of the focusable elements on the window.
+///
+/// The bounds are recorded by a [`Tracker`](Tracker) around the widget of the element while it
+/// is drawn. [`focus`](Registry::focus) moves the focus to the nearest element in the direction
+/// of an arrow key, all other directions follow the focus chain.
+///
+/// Clones of a registry share their bounds, so the registry can be stored in the application
+/// state and handed out to the widgets in `view`.
+///
+/// Call [`next_frame`](Registry::next_frame) at the start of `view`. The bounds of the previous
+/// frame are kept until the first element of the new frame is drawn and are dropped then, so
+/// elements that are not shown anymore can't be selected.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    /// The recorded bounds, shared between the clones.
+    entries: Rc<RefCell<Entries>>,
+}
+
+/// The recorded bounds of a [`Registry`](Registry).
+#[derive(Debug, Default)]
+struct Entries {
+    /// The recorded bounds by the path to their element.
+    bounds: HashMap<FocusPath, Rectangle>,
+    /// The current frame.
+    frame: u64,
+    /// The frame the bounds were recorded in.
+    recorded: u64,
+}
+
+impl Registry {
+    /// Creates a new and empty [`Registry`](Registry).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps the widget of the element at the given path into a [`Tracker`](Tracker) that records
+    /// the bounds of the widget in this registry.
+    pub fn track<'a, Message, Renderer, T>(
+        &self,
+        path: FocusPath,
+        content: T,
+    ) -> Tracker<'a, Message, Renderer>
+    where
+        Renderer: iced_native::Renderer,
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Tracker {
+            registry: self.clone(),
+            path,
+            content: content.into(),
+        }
+    }
+
+    /// Starts a new frame. Call it at the start of `view`.
+    ///
+    /// The bounds of the previous frame are dropped, as soon as the bounds of the first element of
+    /// the new frame are recorded.
+    pub fn next_frame(&self) {
+        let mut entries = self.entries.borrow_mut();
+        entries.frame = entries.frame.wrapping_add(1);
+    }
+
+    /// Records the bounds of the element at the given path, replacing its previous bounds.
+    pub fn insert(&self, path: FocusPath, bounds: Rectangle) {
+        let mut entries = self.entries.borrow_mut();
+
+        if entries.recorded != entries.frame {
+            entries.recorded = entries.frame;
+            entries.bounds.clear();
+        }
+
+        let _ = entries.bounds.insert(path, bounds);
+    }
+
+    /// Forgets the bounds of the element at the given path, e.g. after it was removed.
+    pub fn remove(&self, path: &[PathSegment]) {
+        let _ = self.entries.borrow_mut().bounds.remove(path);
+    }
+
+    /// Forgets the bounds of every element.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().bounds.clear();
+    }
+
+    /// Returns the recorded bounds of the element at the given path.
+    #[must_use]
+    pub fn get(&self, path: &[PathSegment]) -> Option<Rectangle> {
+        self.entries.borrow().bounds.get(path).copied()
+    }
+
+    /// Returns the paths of the elements in the given direction of the element at the given path,
+    /// the best candidate first.
+    ///
+    /// If the element itself was not recorded, the bounds of the closest recorded parent are used.
+    /// Only the arrow directions select any candidates.
+    #[must_use]
+    pub fn candidates(&self, path: &[PathSegment], direction: Direction) -> Vec<FocusPath> {
+        let entries = &self.entries.borrow().bounds;
+
+        let origin = (0..=path.len())
+            .rev()
+            .find_map(|len| entries.get_key_value(&path[..len]));
+
+        origin.map_or_else(Vec::new, |(origin, bounds)| {
+            rank(
+                *bounds,
+                direction,
+                entries
+                    .iter()
+                    .filter(|(other, _)| *other != origin)
+                    .map(|(other, bounds)| (other.clone(), *bounds)),
+            )
+        })
+    }
+
+    /// Request a focus for the given direction on the given focusable element.
+    ///
+    /// The arrow directions move the focus to the nearest recorded element that accepts it.
+    /// They are ignored, if no element has the focus or if there is no element in this
+    /// direction. All other directions are passed to [`Focus::focus`](Focus::focus).
+    pub fn focus<T>(&self, element: &mut T, direction: Direction) -> State
+    where
+        T: Focus + ?Sized,
+    {
+        match direction {
+            Direction::Up | Direction::Down | Direction::Left | Direction::Right => {}
+            Direction::Forwards
+            | Direction::Backwards
+            | Direction::First
+            | Direction::Last
+            | Direction::RowStart
+            | Direction::RowEnd => return element.focus(direction),
+        }
+
+        let current = match element.focused_path() {
+            Some(current) => current,
+            None => return State::Ignored,
+        };
+
+        self.candidates(&current, direction)
+            .iter()
+            .map(|path| element.focus_path(path))
+            .find(|state| *state == State::Kept)
+            .unwrap_or(State::Ignored)
+    }
+}
+
+/// Ranks the candidates in the given direction of the element with the given bounds, the best
+/// candidate first.
+///
+/// A candidate lies in the direction, if it starts behind the edge of the element facing the
+/// direction. Candidates that overlap the element on the other axis, e.g. the elements of the
+/// same column when moving up or down, are preferred and ordered by their distance. The
+/// remaining candidates are ordered by their distance with the offset on the other axis
+/// weighted twice, so that elements of the same row or column win over diagonal ones. Equal
+/// candidates are ordered from top to bottom and from left to right.
+pub fn rank<K, I>(from: Rectangle, direction: Direction, candidates: I) -> Vec<K>
+where
+    I: IntoIterator<Item = (K, Rectangle)>,
+{
+    let mut scored: Vec<(Score, Rectangle, K)> = candidates
+        .into_iter()
+        .filter_map(|(key, bounds)| {
+            Score::new(from, bounds, direction).map(|score| (score, bounds, key))
+        })
+        .collect();
+
+    scored.sort_by(|(a, a_bounds, _), (b, b_bounds, _)| {
+        a.cmp(b)
+            .then_with(|| compare(a_bounds.y, b_bounds.y))
+            .then_with(|| compare(a_bounds.x, b_bounds.x))
+    });
+    scored.into_iter().map(|(_, _, key)| key).collect()
+}
+
+/// Returns the best candidate in the given direction of the element with the given bounds.
+///
+/// See [`rank`](rank) for the order of the candidates.
+pub fn nearest<K, I>(from: Rectangle, direction: Direction, candidates: I) -> Option<K>
+where
+    I: IntoIterator<Item = (K, Rectangle)>,
+{
+    rank(from, direction, candidates).into_iter().next()
+}
+
+/// The score of a candidate in a direction, the lower the better.
+#[derive(Clone, Copy, Debug)]
+struct Score {
+    /// The distance between the facing edges along the direction.
+    distance: f32,
+    /// The offset between the candidate and the element on the other axis.
+    offset: f32,
+    /// The length of the overlap on the other axis.
+    overlap: f32,
+}
+
+impl Score {
+    /// Scores the candidate with the given bounds, if it lies in the given direction.
+    fn new(from: Rectangle, to: Rectangle, direction: Direction) -> Option<Self> {
+        // The edges along the direction and the ranges on the other axis.
+        let (distance, from_range, to_range) = match direction {
+            Direction::Up => (
+                from.y - (to.y + to.height),
+                (from.x, from.x + from.width),
+                (to.x, to.x + to.width),
+            ),
+            Direction::Down => (
+                to.y - (from.y + from.height),
+                (from.x, from.x + from.width),
+                (to.x, to.x + to.width),
+            ),
+            Direction::Left => (
+                from.x - (to.x + to.width),
+                (from.y, from.y + from.height),
+                (to.y, to.y + to.height),
+            ),
+            Direction::Right => (
+                to.x - (from.x + from.width),
+                (from.y, from.y + from.height),
+                (to.y, to.y + to.height),
+            ),
+            Direction::Forwards
+            | Direction::Backwards
+            | Direction::First
+            | Direction::Last
+            | Direction::RowStart
+            | Direction::RowEnd => return None,
+        };
+
+        if distance < 0.0 {
+            return None;
+        }
+
+        let overlap = from_range.1.min(to_range.1) - from_range.0.max(to_range.0);
+        let offset = (to_range.0 - from_range.1)
+            .max(from_range.0 - to_range.1)
+            .max(0.0);
+
+        Some(Self {
+            distance,
+            offset,
+            overlap: overlap.max(0.0),
+        })
+    }
+
+    /// Compares two scores, the better one first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let overlapping = |score: &Self| score.overlap > 0.0;
+
+        match (overlapping(self), overlapping(other)) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (true, true) => compare(self.distance, other.distance)
+                .then_with(|| compare(other.overlap, self.overlap)),
+            (false, false) => compare(
+                self.distance + 2.0 * self.offset,
+                other.distance + 2.0 * other.offset,
+            ),
+        }
+    }
+}
+
+/// Compares two distances, treating `NaN` as equal.
+fn compare(a: f32, b: f32) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// A widget that records the bounds of its content in a [`Registry`](Registry) while it is drawn.
+///
+/// Create it with [`Registry::track`](Registry::track).
+#[allow(missing_debug_implementations)]
+pub struct Tracker<'a, Message, Renderer> {
+    /// The registry to record the bounds in.
+    registry: Registry,
+    /// The path to the focusable element of the content.
+    path: FocusPath,
+    /// The wrapped content.
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Tracker<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.registry.insert(self.path.clone(), layout.bounds());
+
+        self.content
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer> From<Tracker<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Message: 'a,
+{
+    fn from(tracker: Tracker<'a, Message, Renderer>) -> Self {
+        Element::new(tracker)
+    }
+}
@@ -0,0 +1,256 @@
+//! Keyboard subscriptions that turn key presses into focus requests.
+use crate::Direction;
+use iced_native::event::{self, Event};
+use iced_native::futures::stream::BoxStream;
+use iced_native::futures::{future, StreamExt};
+use iced_native::keyboard::{self, KeyCode, Modifiers};
+use iced_native::subscription::{EventStream, Recipe};
+use iced_native::{Hasher, Subscription};
+
+/// Returns a [`Subscription`](Subscription) that produces a message for every focus request
+/// of the default [`KeyBindings`](KeyBindings).
+///
+/// `Tab` requests the focus forwards and `Shift+Tab` backwards.
+pub fn keyboard<Message>(f: fn(Direction) -> Message) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    keyboard_with(KeyBindings::default(), f)
+}
+
+/// Returns a [`Subscription`](Subscription) that produces a message for every focus request
+/// of the given [`KeyBindings`](KeyBindings).
+pub fn keyboard_with<Message>(
+    bindings: KeyBindings,
+    f: fn(Direction) -> Message,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(Keys { bindings, f })
+}
+
+/// Returns a [`Subscription`](Subscription) that produces a message for every key press that
+/// was not captured by a widget.
+///
+/// Use it to trigger the [`Action`](crate::widget::Action) of a focused widget.
+pub fn keys<Message>(f: fn(KeyCode) -> Message) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(KeyPresses { f })
+}
+
+/// A key press that requests the focus into a direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// The pressed key.
+    pub key_code: KeyCode,
+    /// The modifiers that must be pressed together with the key.
+    pub modifiers: Modifiers,
+    /// The direction of the focus request.
+    pub direction: Direction,
+}
+
+impl std::hash::Hash for KeyBinding {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key_code.hash(state);
+        self.modifiers.shift.hash(state);
+        self.modifiers.control.hash(state);
+        self.modifiers.alt.hash(state);
+        self.modifiers.logo.hash(state);
+        self.direction.hash(state);
+    }
+}
+
+/// The mapping of key presses to focus requests.
+///
+/// The default bindings request the focus forwards on `Tab` and backwards on `Shift+Tab`.
+///
+/// Key presses that were already captured by a widget are mapped as well, because a focused
+/// `TextInput` captures every key press, including `Tab`. See
+/// [`ignore_captured`](KeyBindings::ignore_captured).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyBindings {
+    /// The bound key presses.
+    bindings: Vec<KeyBinding>,
+    /// If key presses that were captured by a widget are ignored.
+    ignore_captured: bool,
+}
+
+impl KeyBindings {
+    /// Creates new [`KeyBindings`](KeyBindings) without any bound key.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            ignore_captured: false,
+        }
+    }
+
+    /// Binds the given key and modifiers to a focus request into the given direction.
+    ///
+    /// A previous binding of the same key and modifiers is replaced.
+    #[must_use]
+    pub fn bind(mut self, key_code: KeyCode, modifiers: Modifiers, direction: Direction) -> Self {
+        self.bindings
+            .retain(|b| b.key_code != key_code || b.modifiers != modifiers);
+        self.bindings.push(KeyBinding {
+            key_code,
+            modifiers,
+            direction,
+        });
+        self
+    }
+
+    /// Removes the binding of the given key and modifiers.
+    #[must_use]
+    pub fn unbind(mut self, key_code: KeyCode, modifiers: Modifiers) -> Self {
+        self.bindings
+            .retain(|b| b.key_code != key_code || b.modifiers != modifiers);
+        self
+    }
+
+    /// Binds the arrow keys to the arrow directions.
+    #[must_use]
+    pub fn arrows(self) -> Self {
+        self.bind(KeyCode::Up, Modifiers::default(), Direction::Up)
+            .bind(KeyCode::Down, Modifiers::default(), Direction::Down)
+            .bind(KeyCode::Left, Modifiers::default(), Direction::Left)
+            .bind(KeyCode::Right, Modifiers::default(), Direction::Right)
+    }
+
+    /// Binds `Home` and `End` to the start and the end of the row.
+    #[must_use]
+    pub fn home_end(self) -> Self {
+        self.bind(KeyCode::Home, Modifiers::default(), Direction::RowStart)
+            .bind(KeyCode::End, Modifiers::default(), Direction::RowEnd)
+    }
+
+    /// Sets whether key presses that were already captured by a widget are ignored.
+    ///
+    /// Disabled by default. Enable it, if a widget should handle the bound keys itself, e.g. a
+    /// text input moving its cursor with the arrow keys. Note that a focused `TextInput` captures
+    /// every key press, so no bound key requests a focus while an input has the focus.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn ignore_captured(mut self, ignore_captured: bool) -> Self {
+        self.ignore_captured = ignore_captured;
+        self
+    }
+
+    /// Returns the bound key presses.
+    #[must_use]
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings
+    }
+
+    /// Returns the direction that is bound to the given key and modifiers.
+    #[must_use]
+    pub fn direction(&self, key_code: KeyCode, modifiers: Modifiers) -> Option<Direction> {
+        self.bindings
+            .iter()
+            .find(|b| b.key_code == key_code && b.modifiers == modifiers)
+            .map(|b| b.direction)
+    }
+
+    /// Returns the direction of the focus request for the given event, if any.
+    #[must_use]
+    pub fn map(&self, event: &Event, status: event::Status) -> Option<Direction> {
+        if self.ignore_captured && status == event::Status::Captured {
+            return None;
+        }
+
+        match *event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => self.direction(key_code, modifiers),
+            _ => None,
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::new()
+            .bind(KeyCode::Tab, Modifiers::default(), Direction::Forwards)
+            .bind(
+                KeyCode::Tab,
+                Modifiers {
+                    shift: true,
+                    ..Modifiers::default()
+                },
+                Direction::Backwards,
+            )
+    }
+}
+
+/// The recipe of the keyboard [`Subscription`](Subscription).
+struct Keys<Message> {
+    /// The mapping of key presses to focus requests.
+    bindings: KeyBindings,
+    /// The function producing the message of a focus request.
+    f: fn(Direction) -> Message,
+}
+
+impl<Message> Recipe<Hasher, (Event, event::Status)> for Keys<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        /// Distinguishes this recipe from the other recipes.
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.bindings.hash(state);
+        self.f.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<'static, Self::Output> {
+        input
+            .filter_map(move |(event, status)| {
+                future::ready(self.bindings.map(&event, status).map(self.f))
+            })
+            .boxed()
+    }
+}
+
+/// The recipe of the key press [`Subscription`](Subscription).
+struct KeyPresses<Message> {
+    /// The function producing the message of a key press.
+    f: fn(KeyCode) -> Message,
+}
+
+impl<Message> Recipe<Hasher, (Event, event::Status)> for KeyPresses<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        /// Distinguishes this recipe from the other recipes.
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.f.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<'static, Self::Output> {
+        input
+            .filter_map(move |(event, status)| {
+                future::ready(match (event, status) {
+                    (
+                        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }),
+                        event::Status::Ignored,
+                    ) => Some((self.f)(key_code)),
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,172 @@
+//! Focus traps that confine the focus to a part of the focus chain, e.g. a modal dialog.
+use crate::{Direction, Focus, FocusPath, PathSegment, State};
+
+/// A trap that confines the focus to the wrapped elements while it is active.
+///
+/// An active trap cycles the focus inside of the wrapped elements and never returns it to
+/// its parent. All focus requests of the surrounding focus chain are routed into the trap,
+/// so that elements behind a modal dialog can't be focused. An inactive trap behaves like
+/// the wrapped elements.
+///
+/// Use [`activate_trap`](activate_trap) and [`release_trap`](release_trap) to restore the
+/// focus of the element that had it before the trap was activated.
+#[derive(Clone, Debug, Default)]
+pub struct FocusTrap<T> {
+    /// The wrapped focusable elements.
+    inner: T,
+    /// If the trap confines the focus.
+    active: bool,
+    /// The path to the element that had the focus before the trap was activated.
+    previous: Option<FocusPath>,
+}
+
+impl<T> FocusTrap<T> {
+    /// Creates a new, inactive [`FocusTrap`](FocusTrap) around the given focusable elements.
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            active: false,
+            previous: None,
+        }
+    }
+
+    /// True, if the [`FocusTrap`](FocusTrap) confines the focus.
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Consumes the [`FocusTrap`](FocusTrap) and returns the wrapped elements.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Focus> FocusTrap<T> {
+    /// Activates the [`FocusTrap`](FocusTrap) and focuses its first element.
+    ///
+    /// `previous` is the path to the element that had the focus before. If the trap is
+    /// already active, the path remembered on the first activation is kept.
+    pub fn activate(&mut self, previous: Option<FocusPath>) -> State {
+        if !self.active {
+            self.active = true;
+            self.previous = previous;
+        }
+
+        self.inner.focus(Direction::First)
+    }
+
+    /// Releases the [`FocusTrap`](FocusTrap), removes the focus from its elements and
+    /// returns the path to the element that had the focus before the trap was activated.
+    pub fn release(&mut self) -> Option<FocusPath> {
+        self.active = false;
+        self.inner.blur();
+        self.previous.take()
+    }
+}
+
+impl<T> std::ops::Deref for FocusTrap<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for FocusTrap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Focus> Focus for FocusTrap<T> {
+    fn focus(&mut self, direction: Direction) -> State {
+        match self.inner.focus(direction) {
+            State::Returned if self.active => match self.inner.focus(direction) {
+                State::Returned => State::Ignored,
+                state => state,
+            },
+            state => state,
+        }
+    }
+
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    fn focus_path(&mut self, path: &[PathSegment]) -> State {
+        self.inner.focus_path(path)
+    }
+
+    fn focused_path(&self) -> Option<FocusPath> {
+        self.inner.focused_path()
+    }
+
+    fn focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.focused_paths()
+    }
+
+    fn disabled_focused_paths(&self) -> Vec<FocusPath> {
+        self.inner.disabled_focused_paths()
+    }
+
+    fn blur(&mut self) {
+        self.inner.blur();
+    }
+
+    fn is_trapping(&self) -> bool {
+        self.active || self.inner.is_trapping()
+    }
+
+    fn has_focus_at(&self, path: &[PathSegment]) -> bool {
+        self.inner.has_focus_at(path)
+    }
+
+    fn focus_from(
+        &mut self,
+        current: &[PathSegment],
+        direction: Direction,
+    ) -> (State, Option<FocusPath>) {
+        match self.inner.focus_from(current, direction) {
+            (State::Returned, _) if self.active => {
+                let state = self.focus(direction);
+                (state, self.inner.focused_path())
+            }
+            result => result,
+        }
+    }
+
+    fn sanitize(&mut self) {
+        self.inner.sanitize();
+    }
+}
+
+/// Activates a [`FocusTrap`](FocusTrap) inside of the given root of the focus chain.
+///
+/// The path to the element that currently has the focus is remembered by the trap, the
+/// focus is removed from every element and the first element of the trap is focused.
+pub fn activate_trap<S, T, F>(root: &mut S, trap: F) -> State
+where
+    S: Focus + ?Sized,
+    T: Focus,
+    F: FnOnce(&mut S) -> &mut FocusTrap<T>,
+{
+    let previous = root.focused_path();
+    root.blur();
+    trap(root).activate(previous)
+}
+
+/// Releases a [`FocusTrap`](FocusTrap) inside of the given root of the focus chain.
+///
+/// The focus goes back to the element that had it before the trap was activated. If that
+/// element does not exist anymore, [`State::Ignored`](State::Ignored) is returned.
+pub fn release_trap<S, T, F>(root: &mut S, trap: F) -> State
+where
+    S: Focus + ?Sized,
+    T: Focus,
+    F: FnOnce(&mut S) -> &mut FocusTrap<T>,
+{
+    trap(root)
+        .release()
+        .map_or(State::Ignored, |path| root.focus_path(&path))
+}
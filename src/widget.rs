@@ -0,0 +1,455 @@
+//! Focusable states for the widgets of iced that can't be focused on their own.
+use crate::{Direction, Focus, State};
+use iced::keyboard::KeyCode;
+use iced::{button, checkbox, pick_list, radio, slider, Color};
+
+/// A focusable [`Button`](iced::Button).
+pub type FocusableButton = Focusable<button::State>;
+/// A focusable [`Checkbox`](iced::Checkbox).
+pub type FocusableCheckbox = Focusable<CheckboxState>;
+/// A focusable [`Radio`](iced::Radio) button.
+pub type FocusableRadio = Focusable<RadioState>;
+/// A focusable [`Slider`](iced::Slider).
+pub type FocusableSlider = Focusable<slider::State>;
+/// A focusable [`PickList`](iced::PickList).
+pub type FocusablePickList<T> = Focusable<pick_list::State<T>>;
+
+/// A wrapper around the state of a widget that adds the widget to the focus chain.
+///
+/// The wrapper keeps its own focus flag, as the widgets of iced can't be focused. Use
+/// [`action`](Focusable::action) to react to the keyboard while the widget has the focus, and
+/// [`indicator`](Focusable::indicator) to highlight the focused widget.
+#[derive(Clone, Debug, Default)]
+pub struct Focusable<S> {
+    /// The wrapped state of the widget.
+    state: S,
+    /// If the widget has the focus.
+    focused: bool,
+}
+
+impl<S> Focusable<S> {
+    /// Creates a new, unfocused [`Focusable`](Focusable) around the given state of a widget.
+    pub const fn new(state: S) -> Self {
+        Self {
+            state,
+            focused: false,
+        }
+    }
+
+    /// True, if the widget has the focus.
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns a style sheet that highlights the border of the widget while it has the focus.
+    ///
+    /// Pass [`DefaultStyle`](DefaultStyle) to highlight the default style of the widget.
+    pub const fn indicator<Style>(&self, style: Style) -> Indicator<Style> {
+        Indicator {
+            style,
+            focused: self.focused,
+            color: Indicator::<Style>::COLOR,
+            width: Indicator::<Style>::WIDTH,
+        }
+    }
+
+    /// Consumes the [`Focusable`](Focusable) and returns the wrapped state.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S: Activation> Focusable<S> {
+    /// Returns the action of the widget for the given key, if the widget has the focus.
+    pub fn action(&self, key_code: KeyCode) -> Option<Action> {
+        if self.focused {
+            S::action(key_code)
+        } else {
+            None
+        }
+    }
+}
+
+impl<S> std::ops::Deref for Focusable<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl<S> std::ops::DerefMut for Focusable<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.state
+    }
+}
+
+impl<S> Focus for Focusable<S> {
+    fn focus(&mut self, direction: Direction) -> State {
+        match direction {
+            Direction::Forwards | Direction::Backwards if self.focused => {
+                self.focused = false;
+                State::Returned
+            }
+            Direction::Forwards | Direction::Backwards | Direction::First | Direction::Last => {
+                self.focused = true;
+                State::Kept
+            }
+            Direction::Up
+            | Direction::Down
+            | Direction::Left
+            | Direction::Right
+            | Direction::RowStart
+            | Direction::RowEnd => State::Ignored,
+        }
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn blur(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// The state of a [`Checkbox`](iced::Checkbox), which has no state in iced.
+///
+/// Also suited for custom toggles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CheckboxState;
+
+/// The state of a [`Radio`](iced::Radio) button, which has no state in iced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RadioState;
+
+/// The action of a focused widget triggered by the keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Press the widget, e.g. a button or a radio button.
+    Press,
+    /// Toggle the widget, e.g. a checkbox.
+    Toggle,
+    /// Increase the value of the widget, e.g. a slider.
+    Increment,
+    /// Decrease the value of the widget, e.g. a slider.
+    Decrement,
+    /// Select the next option of the widget, e.g. a pick list.
+    Next,
+    /// Select the previous option of the widget, e.g. a pick list.
+    Previous,
+}
+
+impl Action {
+    /// Applies an [`Increment`](Action::Increment) or [`Decrement`](Action::Decrement) to
+    /// the given value of a slider.
+    ///
+    /// The value is clamped to the given range. All other actions return the value unchanged.
+    #[must_use]
+    pub fn step(self, value: f64, step: f64, range: std::ops::RangeInclusive<f64>) -> f64 {
+        let value = match self {
+            Self::Increment => value + step,
+            Self::Decrement => value - step,
+            _ => return value,
+        };
+
+        value.max(*range.start()).min(*range.end())
+    }
+
+    /// Applies a [`Next`](Action::Next) or [`Previous`](Action::Previous) to the given options
+    /// of a pick list.
+    ///
+    /// Returns the newly selected option, if any. The selection stops at the first and the last
+    /// option. If nothing is selected, the first option is selected.
+    pub fn select<'a, T: PartialEq>(self, options: &'a [T], selected: Option<&T>) -> Option<&'a T> {
+        let current = selected.and_then(|selected| options.iter().position(|o| o == selected));
+
+        let index = match (self, current) {
+            (Self::Next, Some(index)) => (index + 1).min(options.len() - 1),
+            (Self::Previous, Some(index)) => index.saturating_sub(1),
+            (Self::Next | Self::Previous, None) => 0,
+            _ => return None,
+        };
+
+        options.get(index)
+    }
+}
+
+/// The state of a widget that reacts to the keyboard.
+pub trait Activation {
+    /// Returns the action of the widget for the given key, if any.
+    fn action(key_code: KeyCode) -> Option<Action>;
+}
+
+impl Activation for button::State {
+    fn action(key_code: KeyCode) -> Option<Action> {
+        match key_code {
+            KeyCode::Space | KeyCode::Enter | KeyCode::NumpadEnter => Some(Action::Press),
+            _ => None,
+        }
+    }
+}
+
+impl Activation for CheckboxState {
+    fn action(key_code: KeyCode) -> Option<Action> {
+        match key_code {
+            KeyCode::Space => Some(Action::Toggle),
+            _ => None,
+        }
+    }
+}
+
+impl Activation for RadioState {
+    fn action(key_code: KeyCode) -> Option<Action> {
+        match key_code {
+            KeyCode::Space => Some(Action::Press),
+            _ => None,
+        }
+    }
+}
+
+impl Activation for slider::State {
+    fn action(key_code: KeyCode) -> Option<Action> {
+        match key_code {
+            KeyCode::Right | KeyCode::Up => Some(Action::Increment),
+            KeyCode::Left | KeyCode::Down => Some(Action::Decrement),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Activation for pick_list::State<T> {
+    fn action(key_code: KeyCode) -> Option<Action> {
+        match key_code {
+            KeyCode::Down => Some(Action::Next),
+            KeyCode::Up => Some(Action::Previous),
+            _ => None,
+        }
+    }
+}
+
+/// A style sheet that highlights the border of a focused widget.
+///
+/// Created by [`Focusable::indicator`](Focusable::indicator).
+#[derive(Clone, Copy, Debug)]
+pub struct Indicator<S> {
+    /// The wrapped style sheet.
+    style: S,
+    /// If the widget has the focus.
+    focused: bool,
+    /// The color of the border of the focused widget.
+    color: Color,
+    /// The width of the border of the focused widget.
+    width: f32,
+}
+
+impl<S> Indicator<S> {
+    /// The default color of the border of the focused widget.
+    const COLOR: Color = Color::from_rgb(0.2, 0.5, 1.0);
+    /// The default width of the border of the focused widget.
+    const WIDTH: f32 = 2.0;
+
+    /// Sets the color of the border of the focused widget.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the width of the border of the focused widget.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the color and the width of the border, depending on the focus.
+    const fn border(&self, color: Color, width: f32) -> (Color, f32) {
+        if self.focused {
+            (self.color, self.width)
+        } else {
+            (color, width)
+        }
+    }
+}
+
+impl<S: button::StyleSheet> button::StyleSheet for Indicator<S> {
+    fn active(&self) -> button::Style {
+        highlight_button(self, self.style.active())
+    }
+
+    fn hovered(&self) -> button::Style {
+        highlight_button(self, self.style.hovered())
+    }
+
+    fn pressed(&self) -> button::Style {
+        highlight_button(self, self.style.pressed())
+    }
+
+    fn disabled(&self) -> button::Style {
+        highlight_button(self, self.style.disabled())
+    }
+}
+
+/// Highlights the border of a focused button.
+fn highlight_button<S>(indicator: &Indicator<S>, style: button::Style) -> button::Style {
+    let (border_color, border_width) = indicator.border(style.border_color, style.border_width);
+    button::Style {
+        border_color,
+        border_width,
+        ..style
+    }
+}
+
+impl<S: checkbox::StyleSheet> checkbox::StyleSheet for Indicator<S> {
+    fn active(&self, is_checked: bool) -> checkbox::Style {
+        highlight_checkbox(self, self.style.active(is_checked))
+    }
+
+    fn hovered(&self, is_checked: bool) -> checkbox::Style {
+        highlight_checkbox(self, self.style.hovered(is_checked))
+    }
+}
+
+/// Highlights the border of a focused checkbox.
+fn highlight_checkbox<S>(indicator: &Indicator<S>, style: checkbox::Style) -> checkbox::Style {
+    let (border_color, border_width) = indicator.border(style.border_color, style.border_width);
+    checkbox::Style {
+        border_color,
+        border_width,
+        ..style
+    }
+}
+
+impl<S: radio::StyleSheet> radio::StyleSheet for Indicator<S> {
+    fn active(&self) -> radio::Style {
+        highlight_radio(self, self.style.active())
+    }
+
+    fn hovered(&self) -> radio::Style {
+        highlight_radio(self, self.style.hovered())
+    }
+}
+
+/// Highlights the border of a focused radio button.
+fn highlight_radio<S>(indicator: &Indicator<S>, style: radio::Style) -> radio::Style {
+    let (border_color, border_width) = indicator.border(style.border_color, style.border_width);
+    radio::Style {
+        border_color,
+        border_width,
+        ..style
+    }
+}
+
+impl<S: slider::StyleSheet> slider::StyleSheet for Indicator<S> {
+    fn active(&self) -> slider::Style {
+        highlight_slider(self, self.style.active())
+    }
+
+    fn hovered(&self) -> slider::Style {
+        highlight_slider(self, self.style.hovered())
+    }
+
+    fn dragging(&self) -> slider::Style {
+        highlight_slider(self, self.style.dragging())
+    }
+}
+
+/// Highlights the border of the handle of a focused slider.
+fn highlight_slider<S>(indicator: &Indicator<S>, style: slider::Style) -> slider::Style {
+    let (border_color, border_width) =
+        indicator.border(style.handle.border_color, style.handle.border_width);
+    slider::Style {
+        handle: slider::Handle {
+            border_color,
+            border_width,
+            ..style.handle
+        },
+        ..style
+    }
+}
+
+impl<S: pick_list::StyleSheet> pick_list::StyleSheet for Indicator<S> {
+    fn menu(&self) -> iced_style::menu::Style {
+        self.style.menu()
+    }
+
+    fn active(&self) -> pick_list::Style {
+        highlight_pick_list(self, self.style.active())
+    }
+
+    fn hovered(&self) -> pick_list::Style {
+        highlight_pick_list(self, self.style.hovered())
+    }
+}
+
+/// Highlights the border of a focused pick list.
+fn highlight_pick_list<S>(indicator: &Indicator<S>, style: pick_list::Style) -> pick_list::Style {
+    let (border_color, border_width) = indicator.border(style.border_color, style.border_width);
+    pick_list::Style {
+        border_color,
+        border_width,
+        ..style
+    }
+}
+
+/// The default style of a widget, to be highlighted by an [`Indicator`](Indicator).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultStyle;
+
+impl button::StyleSheet for DefaultStyle {
+    fn active(&self) -> button::Style {
+        Box::<dyn button::StyleSheet>::default().active()
+    }
+}
+
+impl checkbox::StyleSheet for DefaultStyle {
+    fn active(&self, is_checked: bool) -> checkbox::Style {
+        Box::<dyn checkbox::StyleSheet>::default().active(is_checked)
+    }
+
+    fn hovered(&self, is_checked: bool) -> checkbox::Style {
+        Box::<dyn checkbox::StyleSheet>::default().hovered(is_checked)
+    }
+}
+
+impl radio::StyleSheet for DefaultStyle {
+    fn active(&self) -> radio::Style {
+        Box::<dyn radio::StyleSheet>::default().active()
+    }
+
+    fn hovered(&self) -> radio::Style {
+        Box::<dyn radio::StyleSheet>::default().hovered()
+    }
+}
+
+impl slider::StyleSheet for DefaultStyle {
+    fn active(&self) -> slider::Style {
+        Box::<dyn slider::StyleSheet>::default().active()
+    }
+
+    fn hovered(&self) -> slider::Style {
+        Box::<dyn slider::StyleSheet>::default().hovered()
+    }
+
+    fn dragging(&self) -> slider::Style {
+        Box::<dyn slider::StyleSheet>::default().dragging()
+    }
+}
+
+impl pick_list::StyleSheet for DefaultStyle {
+    fn menu(&self) -> iced_style::menu::Style {
+        Box::<dyn pick_list::StyleSheet>::default().menu()
+    }
+
+    fn active(&self) -> pick_list::Style {
+        Box::<dyn pick_list::StyleSheet>::default().active()
+    }
+
+    fn hovered(&self) -> pick_list::Style {
+        Box::<dyn pick_list::StyleSheet>::default().hovered()
+    }
+}
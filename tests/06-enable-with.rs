@@ -61,15 +61,20 @@ fn test_struct() {
 
     example.enable_two = false;
     assert!(!example.has_focus());
-    assert!(example.text_input_two.has_focus()); // TODO: this should not be true!
+    assert!(example.text_input_two.has_focus());
+
+    example.sanitize();
+    assert!(!example.has_focus());
+    assert!(!example.text_input_one.has_focus());
+    assert!(!example.text_input_two.has_focus());
 
     example.enable_two = true;
     assert_eq!(
         example.focus(iced_focus::Direction::Forwards),
-        iced_focus::State::Returned
+        iced_focus::State::Kept
     );
-    assert!(!example.has_focus());
-    assert!(!example.text_input_one.has_focus());
+    assert!(example.has_focus());
+    assert!(example.text_input_one.has_focus());
     assert!(!example.text_input_two.has_focus());
 }
 
@@ -131,16 +136,24 @@ fn test_tuple_struct() {
 
     example.1 = false;
     assert!(!example.has_focus());
-    assert!(example.2.has_focus()); // TODO: this should not be true!
+
+    // The focus request sanitizes the disabled field before searching the focus.
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.has_focus());
+    assert!(example.0.has_focus());
+    assert!(!example.2.has_focus());
 
     example.1 = true;
     assert_eq!(
         example.focus(iced_focus::Direction::Forwards),
-        iced_focus::State::Returned
+        iced_focus::State::Kept
     );
-    assert!(!example.has_focus());
+    assert!(example.has_focus());
     assert!(!example.0.has_focus());
-    assert!(!example.2.has_focus());
+    assert!(example.2.has_focus());
 }
 
 #[derive(Focus)]
@@ -260,6 +273,17 @@ fn test_enum_first() {
     }
     assert!(!example.has_focus());
 
+    example.sanitize();
+    if let ExampleEnum::First {
+        ref text_input_one,
+        ref text_input_two,
+        ..
+    } = example
+    {
+        assert!(!text_input_one.has_focus());
+        assert!(!text_input_two.has_focus());
+    }
+
     if let ExampleEnum::First {
         ref mut enable_two, ..
     } = example
@@ -268,16 +292,16 @@ fn test_enum_first() {
     }
     assert_eq!(
         example.focus(iced_focus::Direction::Forwards),
-        iced_focus::State::Returned
+        iced_focus::State::Kept
     );
-    assert!(!example.has_focus());
+    assert!(example.has_focus());
     if let ExampleEnum::First {
         ref text_input_one,
         ref text_input_two,
         ..
     } = example
     {
-        assert!(!text_input_one.has_focus());
+        assert!(text_input_one.has_focus());
         assert!(!text_input_two.has_focus());
     }
 }
@@ -338,17 +362,27 @@ fn test_enum_second() {
     }
     assert!(!example.has_focus());
 
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.has_focus());
+    if let ExampleEnum::Second(ref text_input_one, _, ref text_input_two) = example {
+        assert!(text_input_one.has_focus());
+        assert!(!text_input_two.has_focus());
+    }
+
     if let ExampleEnum::Second(_, ref mut enable_two, _) = example {
         *enable_two = true;
     }
     assert_eq!(
         example.focus(iced_focus::Direction::Forwards),
-        iced_focus::State::Returned
+        iced_focus::State::Kept
     );
-    assert!(!example.has_focus());
+    assert!(example.has_focus());
     if let ExampleEnum::Second(ref text_input_one, _, ref text_input_two) = example {
         assert!(!text_input_one.has_focus());
-        assert!(!text_input_two.has_focus());
+        assert!(text_input_two.has_focus());
     }
 }
 
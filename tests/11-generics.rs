@@ -62,4 +62,120 @@ enum ExampleEnum<'a, 'b: 'a, T: Default, const N: usize> {
     },
 }
 
-fn main() {}
+#[derive(Default, Focus)]
+#[allow(unused)]
+struct ExampleWhere<T>
+where
+    T: Default,
+{
+    #[focus(enable)]
+    text_input: text_input::State,
+    generic: T,
+}
+
+#[derive(Default, Focus)]
+#[allow(unused)]
+struct ExampleDefault<T: Default = u8, U = String> {
+    #[focus(enable)]
+    text_input: text_input::State,
+    generic: T,
+    other: U,
+}
+
+#[derive(Default, Focus)]
+struct ExampleField<T: Focus> {
+    #[focus(enable)]
+    inner: T,
+    #[focus(enable)]
+    text_input: text_input::State,
+}
+
+#[derive(Default, Focus)]
+#[focus(bound = "T: Focus, U: Focus")]
+struct ExampleBound<T, U> {
+    #[focus(enable)]
+    first: T,
+    #[focus(enable)]
+    second: Vec<U>,
+}
+
+#[derive(Focus)]
+#[focus(bound = "T: Focus")]
+#[allow(unused)]
+enum ExampleEnumBound<T, U = u8>
+where
+    U: Default,
+{
+    Inner {
+        #[focus(enable)]
+        inner: T,
+        other: U,
+    },
+    Input(#[focus(enable)] text_input::State),
+}
+
+fn test_where() {
+    let mut example = ExampleWhere::<String>::default();
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input.has_focus());
+}
+
+fn test_default() {
+    let mut example: ExampleDefault = ExampleDefault::default();
+    assert_eq!(example.generic, 0_u8);
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input.has_focus());
+}
+
+fn test_field() {
+    let mut example = ExampleField::<ExampleWhere<u8>>::default();
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.inner.has_focus());
+    assert!(!example.text_input.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(!example.inner.has_focus());
+    assert!(example.text_input.has_focus());
+}
+
+fn test_bound() {
+    let mut example = ExampleBound::<text_input::State, text_input::State> {
+        first: text_input::State::new(),
+        second: vec![text_input::State::new(); 2],
+    };
+    assert_eq!(
+        example.focus(iced_focus::Direction::Last),
+        iced_focus::State::Kept
+    );
+    assert!(!example.first.has_focus());
+    assert!(example.second[1].has_focus());
+
+    let mut example = ExampleEnumBound::<text_input::State>::Inner {
+        inner: text_input::State::new(),
+        other: 0,
+    };
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.has_focus());
+}
+
+fn main() {
+    test_where();
+    test_default();
+    test_field();
+    test_bound();
+}
@@ -0,0 +1,128 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Default, Focus)]
+#[focus(cycle)]
+struct ExampleStruct {
+    #[focus(enable)]
+    text_input_one: text_input::State,
+    #[focus(enable)]
+    text_input_two: text_input::State,
+}
+
+fn test_struct() {
+    let mut example = ExampleStruct::default();
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_one.has_focus());
+    assert!(!example.text_input_two.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(!example.text_input_one.has_focus());
+    assert!(example.text_input_two.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_one.has_focus());
+    assert!(!example.text_input_two.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    assert!(!example.text_input_one.has_focus());
+    assert!(example.text_input_two.has_focus());
+}
+
+#[derive(Focus)]
+#[focus(cycle)]
+enum ExampleEnum {
+    Unit,
+    Named {
+        #[focus(enable)]
+        text_input_one: text_input::State,
+        #[focus(enable)]
+        text_input_two: text_input::State,
+    },
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Unit;
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Ignored
+    );
+
+    let mut example = ExampleEnum::Named {
+        text_input_one: text_input::State::new(),
+        text_input_two: text_input::State::new(),
+    };
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    if let ExampleEnum::Named {
+        ref text_input_one,
+        ref text_input_two,
+    } = example
+    {
+        assert!(!text_input_one.has_focus());
+        assert!(text_input_two.has_focus());
+    }
+}
+
+fn test_wrapper() {
+    let mut example = iced_focus::Cycle::new(vec![text_input::State::new(); 3]);
+    assert!(!example.has_focus());
+
+    for index in 0..3 {
+        assert_eq!(
+            example.focus(iced_focus::Direction::Forwards),
+            iced_focus::State::Kept
+        );
+        assert!(example[index].has_focus());
+    }
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example[0].has_focus());
+    assert!(!example[2].has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    assert!(!example[0].has_focus());
+    assert!(example[2].has_focus());
+
+    let mut empty = iced_focus::Cycle::new(Vec::<text_input::State>::new());
+    assert_eq!(
+        empty.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Ignored
+    );
+}
+
+fn main() {
+    test_struct();
+    test_enum();
+    test_wrapper();
+}
@@ -0,0 +1,144 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, State};
+
+#[derive(Default, Focus)]
+struct Inner {
+    #[focus(enable)]
+    text_input_one: text_input::State,
+    #[focus(enable)]
+    text_input_two: text_input::State,
+}
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    text_input: text_input::State,
+    #[focus(enable)]
+    inner: Inner,
+    #[focus(enable)]
+    text_inputs: Vec<text_input::State>,
+    #[focus(enable)]
+    array: [text_input::State; 2],
+    #[focus(enable)]
+    option: Option<text_input::State>,
+    #[focus(enable)]
+    boxed: Box<Inner>,
+}
+
+fn test_struct() {
+    let mut example = Example {
+        text_inputs: vec![text_input::State::new(); 2],
+        option: Some(text_input::State::new()),
+        ..Example::default()
+    };
+
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.boxed.text_input_two.has_focus());
+    assert!(!example.text_input.has_focus());
+
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.boxed.text_input_two.has_focus());
+
+    assert_eq!(example.focus(Direction::First), State::Kept);
+    assert!(example.text_input.has_focus());
+    assert!(!example.boxed.has_focus());
+
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.inner.text_input_two.has_focus());
+
+    assert_eq!(example.focus(Direction::First), State::Kept);
+    assert!(example.text_input.has_focus());
+    assert!(!example.inner.has_focus());
+
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.boxed.text_input_two.has_focus());
+    assert!(!example.text_input.has_focus());
+
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.boxed.text_input_one.has_focus());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.option.has_focus());
+
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.boxed.text_input_two.has_focus());
+    assert!(!example.option.has_focus());
+    assert!(example.has_focus());
+}
+
+fn test_collections() {
+    let mut text_inputs = vec![text_input::State::new(); 3];
+    assert_eq!(text_inputs.focus(Direction::Last), State::Kept);
+    assert!(text_inputs[2].has_focus());
+    assert_eq!(text_inputs.focus(Direction::First), State::Kept);
+    assert!(text_inputs[0].has_focus());
+    assert!(!text_inputs[2].has_focus());
+
+    let mut empty: Vec<text_input::State> = Vec::new();
+    assert_eq!(empty.focus(Direction::First), State::Ignored);
+    assert_eq!(empty.focus(Direction::Last), State::Ignored);
+
+    let mut array = [text_input::State::new(), text_input::State::new()];
+    assert_eq!(array.focus(Direction::First), State::Kept);
+    assert!(array[0].has_focus());
+    assert_eq!(array.focus(Direction::Last), State::Kept);
+    assert!(!array[0].has_focus());
+    assert!(array[1].has_focus());
+
+    let mut option: Option<text_input::State> = None;
+    assert_eq!(option.focus(Direction::First), State::Ignored);
+
+    let mut options = vec![None, Some(text_input::State::new()), None];
+    assert_eq!(options.focus(Direction::First), State::Kept);
+    assert!(options[1].has_focus());
+    assert_eq!(options.focus(Direction::Last), State::Kept);
+    assert!(options[1].has_focus());
+}
+
+#[derive(Focus)]
+enum ExampleEnum {
+    Unit,
+    Named {
+        #[focus(enable)]
+        text_input_one: text_input::State,
+        #[focus(enable)]
+        text_input_two: text_input::State,
+    },
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Unit;
+    assert_eq!(example.focus(Direction::First), State::Ignored);
+    assert_eq!(example.focus(Direction::Last), State::Ignored);
+
+    let mut example = ExampleEnum::Named {
+        text_input_one: text_input::State::new(),
+        text_input_two: text_input::State::new(),
+    };
+
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    if let ExampleEnum::Named {
+        ref text_input_one,
+        ref text_input_two,
+    } = example
+    {
+        assert!(!text_input_one.has_focus());
+        assert!(text_input_two.has_focus());
+    }
+
+    assert_eq!(example.focus(Direction::First), State::Kept);
+    if let ExampleEnum::Named {
+        ref text_input_one,
+        ref text_input_two,
+    } = example
+    {
+        assert!(text_input_one.has_focus());
+        assert!(!text_input_two.has_focus());
+    }
+}
+
+fn main() {
+    test_struct();
+    test_collections();
+    test_enum();
+}
@@ -0,0 +1,119 @@
+use iced::text_input;
+use iced_focus::{FocusPath, Focus, State};
+
+#[derive(Default, Focus)]
+struct Row(
+    #[focus(enable)] text_input::State,
+    #[focus(enable)] text_input::State,
+);
+
+#[derive(Focus)]
+enum Details {
+    Empty,
+    Filled {
+        #[focus(enable)]
+        email: text_input::State,
+    },
+}
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable)]
+    rows: Vec<Row>,
+    #[focus(enable)]
+    details: Details,
+    #[focus(enable)]
+    optional: Option<text_input::State>,
+    enable_hidden: bool,
+    #[focus(enable = "self.enable_hidden")]
+    hidden: text_input::State,
+}
+
+impl Example {
+    fn enable_hidden(&self) -> bool {
+        self.enable_hidden
+    }
+}
+
+fn main() {
+    let mut example = Example {
+        name: text_input::State::new(),
+        rows: vec![Row::default(), Row::default()],
+        details: Details::Filled {
+            email: text_input::State::new(),
+        },
+        optional: None,
+        enable_hidden: false,
+        hidden: text_input::State::new(),
+    };
+
+    let path = FocusPath::new().name("rows").index(1).index(0);
+    assert_eq!(path.to_string(), "rows.1.0");
+    assert_eq!(example.focus_path(&path), State::Kept);
+    assert!(example.rows[1].0.has_focus());
+    assert!(!example.name.has_focus());
+
+    assert_eq!(example.focus_path(&FocusPath::new().name("name")), State::Kept);
+    assert!(example.name.has_focus());
+    assert!(!example.rows.has_focus());
+
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("details").name("email")),
+        State::Kept
+    );
+    assert!(example.details.has_focus());
+    assert!(!example.name.has_focus());
+
+    // Paths that do not lead to a focusable element keep the current focus.
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("rows").index(2)),
+        State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("unknown")),
+        State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("optional")),
+        State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("hidden")),
+        State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("name").index(0)),
+        State::Ignored
+    );
+    assert!(example.details.has_focus());
+
+    example.enable_hidden = true;
+    assert_eq!(example.focus_path(&FocusPath::new().name("hidden")), State::Kept);
+    assert!(example.hidden.has_focus());
+    assert!(!example.details.has_focus());
+
+    // An empty path focuses the first element of the addressed element.
+    assert_eq!(example.focus_path(&FocusPath::new().name("rows")), State::Kept);
+    assert!(example.rows[0].0.has_focus());
+    assert!(!example.hidden.has_focus());
+
+    example.details = Details::Empty;
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("details").name("email")),
+        State::Ignored
+    );
+    assert!(example.rows[0].0.has_focus());
+
+    let mut text_inputs = vec![text_input::State::new(); 3];
+    assert_eq!(text_inputs.focus_path(&FocusPath::new().index(2)), State::Kept);
+    assert!(text_inputs[2].has_focus());
+    assert_eq!(text_inputs.focus_path(&FocusPath::new().index(1)), State::Kept);
+    assert!(text_inputs[1].has_focus());
+    assert!(!text_inputs[2].has_focus());
+    assert_eq!(
+        text_inputs.focus_path(&FocusPath::new().name("first")),
+        State::Ignored
+    );
+}
@@ -0,0 +1,95 @@
+use iced::text_input;
+use iced_focus::{Focus, FocusPath};
+
+#[derive(Default, Focus)]
+struct Row(
+    #[focus(enable)] text_input::State,
+    #[focus(enable)] text_input::State,
+);
+
+#[derive(Focus)]
+enum Details {
+    Empty,
+    Filled {
+        #[focus(enable)]
+        email: text_input::State,
+    },
+}
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable)]
+    rows: Vec<Row>,
+    #[focus(enable)]
+    details: Details,
+    #[focus(enable)]
+    optional: Option<Box<text_input::State>>,
+    enable_hidden: bool,
+    #[focus(enable = "self.enable_hidden")]
+    hidden: text_input::State,
+}
+
+impl Example {
+    fn enable_hidden(&self) -> bool {
+        self.enable_hidden
+    }
+}
+
+fn main() {
+    let mut example = Example {
+        name: text_input::State::new(),
+        rows: vec![Row::default(), Row::default()],
+        details: Details::Filled {
+            email: text_input::State::new(),
+        },
+        optional: Some(Box::new(text_input::State::new())),
+        enable_hidden: true,
+        hidden: text_input::State::new(),
+    };
+    assert_eq!(example.focused_path(), None);
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(example.focused_path(), Some(FocusPath::new().name("name")));
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("rows").index(1).index(0))
+    );
+    assert_eq!(
+        example.rows.focused_path(),
+        Some(FocusPath::new().index(1).index(0))
+    );
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("details").name("email"))
+    );
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(example.focused_path(), Some(FocusPath::new().name("optional")));
+
+    let _ = example.focus(iced_focus::Direction::Last);
+    assert_eq!(example.focused_path(), Some(FocusPath::new().name("hidden")));
+
+    example.enable_hidden = false;
+    assert_eq!(example.focused_path(), None);
+
+    // The path returned by `focused_path` can be passed to `focus_path`.
+    example.enable_hidden = true;
+    let path = FocusPath::new().name("rows").index(0).index(1);
+    assert_eq!(example.focus_path(&path), iced_focus::State::Kept);
+    assert_eq!(example.focused_path(), Some(path));
+
+    example.details = Details::Empty;
+    assert_eq!(example.details.focused_path(), None);
+
+    let text_input = text_input::State::focused();
+    assert_eq!(text_input.focused_path(), Some(FocusPath::new()));
+}
@@ -0,0 +1,120 @@
+use iced::text_input;
+use iced_focus::{Cycle, Direction, Focus, State};
+
+#[derive(Focus)]
+enum Dialog {
+    Closed,
+    Open(#[focus(enable)] text_input::State),
+}
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    text_inputs: Vec<text_input::State>,
+    #[focus(enable)]
+    cycle: Cycle<[text_input::State; 2]>,
+    #[focus(enable)]
+    dialog: Dialog,
+    #[focus(enable)]
+    optional: Option<Box<text_input::State>>,
+    enable_hidden: bool,
+    #[focus(enable = "self.enable_hidden")]
+    hidden: text_input::State,
+}
+
+impl Example {
+    fn enable_hidden(&self) -> bool {
+        self.enable_hidden
+    }
+}
+
+/// A hand-written leaf that toggles its focus for every direction.
+struct Toggle(bool);
+
+impl Focus for Toggle {
+    fn focus(&mut self, _direction: Direction) -> State {
+        self.0 = !self.0;
+        if self.0 {
+            State::Kept
+        } else {
+            State::Returned
+        }
+    }
+
+    fn has_focus(&self) -> bool {
+        self.0
+    }
+
+    fn blur(&mut self) {
+        self.0 = false;
+    }
+}
+
+fn test_custom_leaf() {
+    let mut toggle = Toggle(true);
+    toggle.blur();
+    assert!(!toggle.has_focus());
+
+    let mut toggles = vec![Toggle(false), Toggle(true)];
+    toggles.blur();
+    assert!(!toggles.has_focus());
+    assert!(!toggles[1].has_focus());
+}
+
+fn test_blur() {
+    let mut example = Example {
+        text_inputs: vec![text_input::State::new(); 2],
+        cycle: Cycle::new([text_input::State::new(), text_input::State::new()]),
+        dialog: Dialog::Open(text_input::State::new()),
+        optional: Some(Box::new(text_input::State::new())),
+        enable_hidden: true,
+        hidden: text_input::State::new(),
+    };
+
+    example.blur();
+    assert!(!example.has_focus());
+
+    assert_eq!(example.focus(Direction::First), State::Kept);
+    example.blur();
+    assert!(!example.has_focus());
+    assert!(!example.text_inputs[0].has_focus());
+
+    for _ in 0..3 {
+        let _ = example.focus(Direction::Forwards);
+    }
+    assert!(example.cycle[0].has_focus());
+    example.blur();
+    assert!(!example.cycle.has_focus());
+
+    let _ = example.focus(Direction::Last);
+    let _ = example.focus(Direction::Backwards);
+    let _ = example.focus(Direction::Backwards);
+    assert!(example.dialog.has_focus());
+    example.blur();
+    assert!(!example.dialog.has_focus());
+
+    let _ = example.focus(Direction::Last);
+    let _ = example.focus(Direction::Backwards);
+    assert!(example.optional.has_focus());
+    example.blur();
+    assert!(!example.optional.has_focus());
+
+    // Disabled elements are blurred as well.
+    let _ = example.focus(Direction::Last);
+    assert!(example.hidden.has_focus());
+    example.enable_hidden = false;
+    example.blur();
+    assert!(!example.hidden.has_focus());
+
+    // Blurring an element without focus keeps it unfocused.
+    example.dialog = Dialog::Closed;
+    example.blur();
+    assert!(!example.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.text_inputs[0].has_focus());
+}
+
+fn main() {
+    test_blur();
+    test_custom_leaf();
+}
@@ -0,0 +1,111 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusPath, PathSegment};
+
+#[derive(Default, Focus)]
+struct ExampleStruct {
+    #[focus(enable)]
+    text_input_one: text_input::State,
+    #[focus(enable, order = 2)]
+    text_input_two: text_input::State,
+    #[focus(enable, order = 1)]
+    text_input_three: text_input::State,
+    enable_four: bool,
+    #[focus(enable = "self.enable_four", order = 0)]
+    text_input_four: text_input::State,
+}
+
+impl ExampleStruct {
+    fn enable_four(&self) -> bool {
+        self.enable_four
+    }
+}
+
+fn test_struct() {
+    let mut example = ExampleStruct {
+        enable_four: true,
+        ..ExampleStruct::default()
+    };
+
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.text_input_four.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.text_input_three.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.text_input_two.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.text_input_one.has_focus());
+    let _ = example.focus(Direction::Backwards);
+    assert!(example.text_input_two.has_focus());
+
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("text_input_two"))
+    );
+    let _ = example.focus_path(&FocusPath::new().name("text_input_one"));
+    assert!(example.text_input_one.has_focus());
+    assert!(!example.text_input_two.has_focus());
+
+    example.enable_four = false;
+    let _ = example.focus(Direction::First);
+    assert!(example.text_input_three.has_focus());
+    let _ = example.focus(Direction::Last);
+    assert!(example.text_input_one.has_focus());
+}
+
+#[derive(Default, Focus)]
+struct ExampleTupleStruct(
+    #[focus(enable, order = 1)] text_input::State,
+    #[focus(enable, order = 0)] text_input::State,
+);
+
+fn test_tuple_struct() {
+    let mut example = ExampleTupleStruct::default();
+
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.1.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.0.has_focus());
+    assert_eq!(
+        example.focused_path().as_deref(),
+        Some(&[PathSegment::Index(0)][..])
+    );
+}
+
+#[derive(Focus)]
+enum ExampleEnum {
+    Named {
+        #[focus(enable, order = 1)]
+        text_input_one: text_input::State,
+        #[focus(enable, order = 0)]
+        text_input_two: text_input::State,
+    },
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Named {
+        text_input_one: text_input::State::new(),
+        text_input_two: text_input::State::new(),
+    };
+
+    let _ = example.focus(Direction::Forwards);
+    let ExampleEnum::Named {
+        ref text_input_one,
+        ref text_input_two,
+    } = example;
+    assert!(!text_input_one.has_focus());
+    assert!(text_input_two.has_focus());
+
+    let _ = example.focus(Direction::Forwards);
+    let ExampleEnum::Named {
+        ref text_input_one,
+        ref text_input_two,
+    } = example;
+    assert!(text_input_one.has_focus());
+    assert!(!text_input_two.has_focus());
+}
+
+fn main() {
+    test_struct();
+    test_tuple_struct();
+    test_enum();
+}
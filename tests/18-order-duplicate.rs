@@ -0,0 +1,12 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable, order = 1)]
+    text_input_one: text_input::State,
+    #[focus(enable, order = 1)]
+    text_input_two: text_input::State,
+}
+
+fn main() {}
@@ -0,0 +1,193 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusGroup, FocusPath, State};
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    before: text_input::State,
+    #[focus(enable)]
+    toolbar: FocusGroup<Vec<text_input::State>>,
+    #[focus(enable)]
+    radios: Radios,
+    #[focus(enable)]
+    after: text_input::State,
+}
+
+#[derive(Default, Focus)]
+#[focus(group, cycle)]
+struct Radios {
+    #[focus(enable)]
+    one: text_input::State,
+    #[focus(enable)]
+    two: text_input::State,
+    #[focus(memory)]
+    memory: Option<FocusPath>,
+}
+
+fn test_wrapper() {
+    let mut example = Example {
+        before: text_input::State::new(),
+        toolbar: FocusGroup::new(vec![text_input::State::new(); 3]),
+        radios: Radios::default(),
+        after: text_input::State::new(),
+    };
+
+    // Arrows are ignored outside of a group.
+    assert_eq!(example.focus(Direction::Right), State::Ignored);
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Right), State::Ignored);
+    assert!(example.before.has_focus());
+
+    // Tab enters the group at its first element.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.toolbar[0].has_focus());
+
+    // Arrows move inside of the group and stop at its ends.
+    assert_eq!(example.focus(Direction::Right), State::Kept);
+    assert!(example.toolbar[1].has_focus());
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert!(example.toolbar[2].has_focus());
+    assert_eq!(example.focus(Direction::Right), State::Kept);
+    assert!(example.toolbar[2].has_focus());
+    assert_eq!(example.focus(Direction::Left), State::Kept);
+    assert!(example.toolbar[1].has_focus());
+
+    // Tab leaves the group as a unit.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(!example.toolbar.has_focus());
+    assert!(example.radios.one.has_focus());
+
+    // Shift+Tab enters the group at the element that had the focus last.
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.toolbar[1].has_focus());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.before.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.toolbar[1].has_focus());
+
+    // The group remembers the element when it is blurred.
+    let _ = example.focus(Direction::Right);
+    example.blur();
+    let _ = example.focus_path(&FocusPath::new().name("before"));
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.toolbar[2].has_focus());
+
+    let mut cycle = FocusGroup::new(vec![text_input::State::new(); 2]).cycle(true);
+    let _ = cycle.focus(Direction::Forwards);
+    assert_eq!(cycle.focus(Direction::Up), State::Kept);
+    assert!(cycle[1].has_focus());
+    assert_eq!(cycle.focus(Direction::Down), State::Kept);
+    assert!(cycle[0].has_focus());
+    assert_eq!(cycle.focus(Direction::Forwards), State::Returned);
+    assert!(!cycle.has_focus());
+}
+
+fn test_derive() {
+    let mut example = Example {
+        before: text_input::State::new(),
+        toolbar: FocusGroup::new(Vec::new()),
+        radios: Radios::default(),
+        after: text_input::State::new(),
+    };
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.radios.one.has_focus());
+
+    // The derived group cycles inside of the group.
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert!(example.radios.two.has_focus());
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert!(example.radios.one.has_focus());
+    assert_eq!(example.focus(Direction::Up), State::Kept);
+    assert!(example.radios.two.has_focus());
+
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.after.has_focus());
+    assert!(!example.radios.has_focus());
+
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.radios.two.has_focus());
+    assert!(example.radios.memory.is_some());
+
+    // Leaving the group with `Last` remembers the element that had the focus.
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert!(example.radios.one.has_focus());
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.after.has_focus());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.radios.one.has_focus());
+
+    // Just like leaving it with a path.
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("before")),
+        State::Kept
+    );
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.radios.two.has_focus());
+}
+
+#[derive(Focus)]
+#[focus(group)]
+enum Choice {
+    Unit,
+    Named {
+        #[focus(enable)]
+        one: text_input::State,
+        #[focus(enable)]
+        two: text_input::State,
+        #[focus(memory)]
+        memory: Option<FocusPath>,
+    },
+    Unnamed(
+        #[focus(enable)] text_input::State,
+        #[focus(enable)] text_input::State,
+        #[focus(memory)] Option<FocusPath>,
+    ),
+}
+
+fn test_enum() {
+    let mut choice = Choice::Unit;
+    assert_eq!(choice.focus(Direction::Forwards), State::Ignored);
+
+    let mut choice = Choice::Named {
+        one: text_input::State::new(),
+        two: text_input::State::new(),
+        memory: None,
+    };
+    let _ = choice.focus(Direction::Forwards);
+    assert_eq!(choice.focus(Direction::Right), State::Kept);
+    assert_eq!(choice.focus(Direction::Forwards), State::Returned);
+    assert_eq!(choice.focus(Direction::Forwards), State::Kept);
+    if let Choice::Named { ref two, .. } = choice {
+        assert!(two.has_focus());
+    }
+
+    // Without a remembered element the group is entered at its first or last element.
+    let mut choice = Choice::Unnamed(text_input::State::new(), text_input::State::new(), None);
+    assert_eq!(choice.focus(Direction::Backwards), State::Kept);
+    assert_eq!(choice.focus(Direction::Left), State::Kept);
+    assert_eq!(choice.focus(Direction::Backwards), State::Returned);
+    assert_eq!(choice.focus(Direction::Backwards), State::Kept);
+    if let Choice::Unnamed(ref one, ref two, ref memory) = choice {
+        assert!(one.has_focus());
+        assert!(!two.has_focus());
+        assert_eq!(memory, &Some(FocusPath::new().index(0)));
+    }
+
+    // Blurring the derived group remembers the focused element as well.
+    assert_eq!(choice.focus(Direction::Right), State::Kept);
+    choice.blur();
+    assert!(!choice.has_focus());
+    assert_eq!(choice.focus(Direction::Forwards), State::Kept);
+    if let Choice::Unnamed(_, ref two, _) = choice {
+        assert!(two.has_focus());
+    }
+}
+
+fn main() {
+    test_wrapper();
+    test_derive();
+    test_enum();
+}
@@ -0,0 +1,130 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusPath, FocusTrap, State};
+
+#[derive(Default, Focus)]
+struct Dialog {
+    #[focus(enable)]
+    ok: text_input::State,
+    #[focus(enable)]
+    cancel: text_input::State,
+}
+
+#[derive(Default, Focus)]
+struct Form {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable)]
+    modal: FocusTrap<Dialog>,
+    #[focus(enable)]
+    email: text_input::State,
+}
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    search: text_input::State,
+    #[focus(enable)]
+    form: Form,
+}
+
+fn test_trap() {
+    let mut example = Example::default();
+
+    // An inactive trap is part of the focus chain.
+    assert!(!example.is_trapping());
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.form.modal.ok.has_focus());
+    let _ = example.focus_path(&FocusPath::new().name("form").name("email"));
+    assert!(example.form.email.has_focus());
+
+    // The trap takes the focus.
+    assert_eq!(
+        iced_focus::activate_trap(&mut example, |e| &mut e.form.modal),
+        State::Kept
+    );
+    assert!(example.is_trapping());
+    assert!(example.form.modal.is_active());
+    assert!(example.form.modal.ok.has_focus());
+    assert!(!example.form.email.has_focus());
+
+    // The focus cycles inside of the trap.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.form.modal.cancel.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.form.modal.ok.has_focus());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.form.modal.cancel.has_focus());
+    assert_eq!(example.focus(Direction::First), State::Kept);
+    assert!(example.form.modal.ok.has_focus());
+    assert_eq!(example.focus(Direction::Last), State::Kept);
+    assert!(example.form.modal.cancel.has_focus());
+    assert!(!example.search.has_focus());
+    assert!(!example.form.name.has_focus());
+
+    // Elements outside of the trap can't be focused.
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("search")),
+        State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("form").name("name")),
+        State::Ignored
+    );
+    assert!(example.form.modal.cancel.has_focus());
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("form").name("modal").name("ok")),
+        State::Kept
+    );
+    assert!(example.form.modal.ok.has_focus());
+
+    // The focus goes back to the element that had it before.
+    assert_eq!(
+        iced_focus::release_trap(&mut example, |e| &mut e.form.modal),
+        State::Kept
+    );
+    assert!(!example.is_trapping());
+    assert!(!example.form.modal.has_focus());
+    assert!(example.form.email.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+}
+
+fn test_wrapper() {
+    let mut trap = FocusTrap::new(vec![text_input::State::new(); 2]);
+    assert_eq!(trap.focus(Direction::Forwards), State::Kept);
+    let _ = trap.focus(Direction::Forwards);
+    assert_eq!(trap.focus(Direction::Forwards), State::Returned);
+
+    let previous = Some(FocusPath::new().index(7));
+    assert_eq!(trap.activate(previous.clone()), State::Kept);
+    assert!(trap[0].has_focus());
+    let _ = trap.focus(Direction::Forwards);
+    assert_eq!(trap.focus(Direction::Forwards), State::Kept);
+    assert!(trap[0].has_focus());
+
+    // A second activation keeps the first remembered path.
+    assert_eq!(trap.activate(None), State::Kept);
+    assert_eq!(trap.release(), previous);
+    assert!(!trap.is_active());
+    assert!(!trap.has_focus());
+
+    // Nothing was focused before the trap was activated.
+    let mut example = Example::default();
+    let _ = iced_focus::activate_trap(&mut example, |e| &mut e.form.modal);
+    assert_eq!(
+        iced_focus::release_trap(&mut example, |e| &mut e.form.modal),
+        State::Ignored
+    );
+    assert!(!example.has_focus());
+
+    // An empty trap never returns the focus.
+    let mut empty: FocusTrap<Vec<text_input::State>> = FocusTrap::new(Vec::new());
+    assert_eq!(empty.activate(None), State::Ignored);
+    assert_eq!(empty.focus(Direction::Forwards), State::Ignored);
+}
+
+fn main() {
+    test_trap();
+    test_wrapper();
+}
@@ -0,0 +1,122 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusHistory, FocusPath, State};
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    search: text_input::State,
+    #[focus(enable = "self.enable_rows")]
+    rows: Vec<text_input::State>,
+    #[focus(enable)]
+    popup: text_input::State,
+    show_rows: bool,
+}
+
+impl Example {
+    fn enable_rows(&self) -> bool {
+        self.show_rows
+    }
+}
+
+fn example() -> FocusHistory<Example> {
+    FocusHistory::new(Example {
+        rows: vec![text_input::State::new(); 3],
+        show_rows: true,
+        ..Example::default()
+    })
+}
+
+fn test_restore() {
+    let mut example = example();
+
+    // Nothing had the focus before.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.history().is_empty());
+    assert_eq!(example.restore_previous(), State::Ignored);
+    assert!(example.search.has_focus());
+
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.rows[1].has_focus());
+    assert_eq!(
+        example.history(),
+        &[
+            FocusPath::new().name("search"),
+            FocusPath::new().name("rows").index(0)
+        ]
+    );
+
+    // The popup takes the focus and gives it back when it closes.
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("popup")),
+        State::Kept
+    );
+    assert_eq!(example.restore_previous(), State::Kept);
+    assert!(example.rows[1].has_focus());
+    assert!(!example.popup.has_focus());
+    assert_eq!(example.restore_previous(), State::Kept);
+    assert!(example.rows[0].has_focus());
+
+    // A request that keeps the focus on the same element is not recorded.
+    let _ = example.focus_path(&FocusPath::new().name("rows").index(0));
+    assert_eq!(example.history(), &[FocusPath::new().name("search")]);
+
+    // Blurring records the focused element as well.
+    example.blur();
+    assert_eq!(example.restore_previous(), State::Kept);
+    assert!(example.rows[0].has_focus());
+}
+
+fn test_fallback() {
+    let mut example = example();
+
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus_path(&FocusPath::new().name("rows").index(2));
+    let _ = example.focus_path(&FocusPath::new().name("popup"));
+
+    // The rebuilt list is shorter, the search field is focused instead.
+    example.rows.truncate(1);
+    assert_eq!(example.restore_previous(), State::Kept);
+    assert!(example.search.has_focus());
+    assert!(example.history().is_empty());
+
+    // Disabled elements are skipped.
+    example.rows = vec![text_input::State::new(); 3];
+    let _ = example.focus(Direction::Forwards);
+    example.clear();
+    let _ = example.focus_path(&FocusPath::new().name("popup"));
+    example.show_rows = false;
+    assert_eq!(example.restore_previous(), State::Ignored);
+    assert!(example.popup.has_focus());
+}
+
+fn test_push_pop() {
+    let mut example = example().limit(2);
+
+    assert!(!example.push());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.push());
+    assert!(example.push());
+    assert_eq!(example.history().len(), 1);
+
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(
+        example.history(),
+        &[
+            FocusPath::new().name("rows").index(0),
+            FocusPath::new().name("rows").index(1)
+        ]
+    );
+
+    assert_eq!(example.pop(), Some(FocusPath::new().name("rows").index(1)));
+    example.clear();
+    assert_eq!(example.pop(), None);
+}
+
+fn main() {
+    test_restore();
+    test_fallback();
+    test_push_pop();
+}
@@ -0,0 +1,179 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusManager, FocusPath, State};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A text input that counts how often it was asked for its focus.
+#[derive(Clone)]
+struct Counted {
+    state: text_input::State,
+    calls: Rc<Cell<usize>>,
+}
+
+impl Focus for Counted {
+    fn focus(&mut self, direction: Direction) -> State {
+        Focus::focus(&mut self.state, direction)
+    }
+
+    fn has_focus(&self) -> bool {
+        self.calls.set(self.calls.get() + 1);
+        self.state.is_focused()
+    }
+
+    fn blur(&mut self) {
+        self.state.unfocus();
+    }
+}
+
+#[derive(Focus)]
+struct Row {
+    #[focus(enable)]
+    name: Counted,
+    #[focus(enable)]
+    email: Counted,
+}
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    search: text_input::State,
+    #[focus(enable)]
+    rows: Vec<Row>,
+    #[focus(enable = "self.enable_footer")]
+    footer: Option<text_input::State>,
+    show_footer: bool,
+}
+
+impl Example {
+    fn enable_footer(&self) -> bool {
+        self.show_footer
+    }
+}
+
+fn example(calls: &Rc<Cell<usize>>) -> Example {
+    let counted = Counted {
+        state: text_input::State::new(),
+        calls: Rc::clone(calls),
+    };
+    let row = Row {
+        name: counted.clone(),
+        email: counted,
+    };
+
+    Example {
+        search: text_input::State::new(),
+        rows: vec![
+            Row {
+                name: row.name.clone(),
+                email: row.email.clone(),
+            };
+            100
+        ],
+        footer: Some(text_input::State::new()),
+        show_footer: true,
+    }
+}
+
+impl Clone for Row {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            email: self.email.clone(),
+        }
+    }
+}
+
+fn test_same_as_focus() {
+    let calls = Rc::new(Cell::new(0));
+    let mut plain = example(&calls);
+    let mut managed = FocusManager::new(example(&calls));
+
+    for direction in [
+        Direction::Forwards,
+        Direction::Forwards,
+        Direction::Forwards,
+        Direction::Backwards,
+        Direction::Last,
+        Direction::Backwards,
+        Direction::Forwards,
+        Direction::Forwards,
+        Direction::First,
+        Direction::Backwards,
+        Direction::Backwards,
+    ]
+    .iter()
+    {
+        assert_eq!(plain.focus(*direction), managed.focus(*direction));
+        assert_eq!(plain.focused_path(), managed.focused_path());
+        assert_eq!(managed.current().cloned(), managed.focused_path());
+    }
+}
+
+fn test_fewer_requests() {
+    let calls = Rc::new(Cell::new(0));
+    let mut example = FocusManager::new(example(&calls));
+
+    let _ = example.focus(Direction::Forwards);
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(
+        example.current(),
+        Some(&FocusPath::new().name("rows").index(0).name("name"))
+    );
+
+    calls.set(0);
+    for _ in 0..100 {
+        let _ = example.focus(Direction::Forwards);
+    }
+
+    // Only the elements along the path and the next element are asked.
+    assert!(calls.get() <= 100 * 4, "{} calls", calls.get());
+    assert_eq!(
+        example.current(),
+        Some(&FocusPath::new().name("rows").index(50).name("name"))
+    );
+}
+
+fn test_resync() {
+    let calls = Rc::new(Cell::new(0));
+    let mut example = FocusManager::new(example(&calls));
+
+    let _ = example.focus(Direction::Forwards);
+
+    // The focus was changed by a mouse click.
+    example.search.unfocus();
+    example.rows[42].email.state.focus();
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.rows[43].name.state.is_focused());
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("rows").index(43).name("name"))
+    );
+
+    // The focused element is disabled now.
+    let _ = example.focus(Direction::Last);
+    example.show_footer = false;
+    assert!(!example.has_focus_at(&FocusPath::new().name("footer")));
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.search.is_focused());
+
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("rows").index(7)),
+        State::Kept
+    );
+    assert_eq!(
+        example.current(),
+        Some(&FocusPath::new().name("rows").index(7).name("name"))
+    );
+
+    example.blur();
+    assert_eq!(example.current(), None);
+    assert!(!example.has_focus());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.rows[99].email.state.is_focused());
+}
+
+fn main() {
+    test_same_as_focus();
+    test_fewer_requests();
+    test_resync();
+}
@@ -0,0 +1,140 @@
+use iced::keyboard::{KeyCode, Modifiers};
+use iced_focus::subscription::{self, KeyBindings};
+use iced_focus::Direction;
+use iced_native::event::Status;
+use iced_native::{keyboard, mouse, Event};
+
+#[derive(Debug, PartialEq)]
+enum Message {
+    Focus(Direction),
+}
+
+fn pressed(key_code: KeyCode, modifiers: Modifiers) -> Event {
+    Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code,
+        modifiers,
+    })
+}
+
+fn shift() -> Modifiers {
+    Modifiers {
+        shift: true,
+        ..Modifiers::default()
+    }
+}
+
+fn control() -> Modifiers {
+    Modifiers {
+        control: true,
+        ..Modifiers::default()
+    }
+}
+
+fn test_default() {
+    let bindings = KeyBindings::default();
+
+    assert_eq!(
+        bindings.map(
+            &pressed(KeyCode::Tab, Modifiers::default()),
+            Status::Ignored
+        ),
+        Some(Direction::Forwards)
+    );
+    assert_eq!(
+        bindings.map(&pressed(KeyCode::Tab, shift()), Status::Ignored),
+        Some(Direction::Backwards)
+    );
+    assert_eq!(
+        bindings.map(&pressed(KeyCode::Tab, control()), Status::Ignored),
+        None
+    );
+    assert_eq!(
+        bindings.map(
+            &pressed(KeyCode::Enter, Modifiers::default()),
+            Status::Ignored
+        ),
+        None
+    );
+    assert_eq!(
+        bindings.map(
+            &Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: KeyCode::Tab,
+                modifiers: Modifiers::default(),
+            }),
+            Status::Ignored
+        ),
+        None
+    );
+    assert_eq!(
+        bindings.map(&Event::Mouse(mouse::Event::CursorEntered), Status::Ignored),
+        None
+    );
+
+    // A focused text input captures every key press, so captured events are mapped by default.
+    assert_eq!(
+        bindings.map(
+            &pressed(KeyCode::Tab, Modifiers::default()),
+            Status::Captured
+        ),
+        Some(Direction::Forwards)
+    );
+    assert_eq!(
+        bindings.map(&pressed(KeyCode::Tab, shift()), Status::Captured),
+        Some(Direction::Backwards)
+    );
+    let bindings = bindings.ignore_captured(true);
+    assert_eq!(
+        bindings.map(
+            &pressed(KeyCode::Tab, Modifiers::default()),
+            Status::Captured
+        ),
+        None
+    );
+}
+
+fn test_custom() {
+    let bindings = KeyBindings::new()
+        .bind(KeyCode::Enter, Modifiers::default(), Direction::Forwards)
+        .bind(KeyCode::Tab, control(), Direction::Forwards)
+        .bind(KeyCode::Tab, control(), Direction::Backwards)
+        .bind(KeyCode::Home, Modifiers::default(), Direction::First)
+        .arrows();
+
+    assert_eq!(bindings.bindings().len(), 7);
+    assert_eq!(
+        bindings.direction(KeyCode::Enter, Modifiers::default()),
+        Some(Direction::Forwards)
+    );
+    assert_eq!(
+        bindings.direction(KeyCode::Tab, control()),
+        Some(Direction::Backwards)
+    );
+    assert_eq!(bindings.direction(KeyCode::Tab, Modifiers::default()), None);
+    assert_eq!(
+        bindings.direction(KeyCode::Home, Modifiers::default()),
+        Some(Direction::First)
+    );
+    assert_eq!(
+        bindings.direction(KeyCode::Left, Modifiers::default()),
+        Some(Direction::Left)
+    );
+
+    let bindings = bindings.unbind(KeyCode::Enter, Modifiers::default());
+    assert_eq!(
+        bindings.direction(KeyCode::Enter, Modifiers::default()),
+        None
+    );
+}
+
+fn test_subscription() {
+    let _: iced::Subscription<Message> = subscription::keyboard(Message::Focus);
+    let _: iced::Subscription<Message> =
+        subscription::keyboard_with(KeyBindings::default().arrows(), Message::Focus);
+    let _: iced::Subscription<KeyCode> = subscription::keys(|key_code| key_code);
+}
+
+fn main() {
+    test_default();
+    test_custom();
+    test_subscription();
+}
@@ -0,0 +1,136 @@
+use iced::keyboard::KeyCode;
+use iced::{button, checkbox, pick_list, radio, slider, text_input, Color};
+use iced_focus::widget::{
+    Action, DefaultStyle, FocusableButton, FocusableCheckbox, FocusablePickList, FocusableRadio,
+    FocusableSlider,
+};
+use iced_focus::{Direction, Focus, Focusable, State};
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    text_input: text_input::State,
+    #[focus(enable)]
+    button: FocusableButton,
+    #[focus(enable)]
+    checkbox: FocusableCheckbox,
+    #[focus(enable)]
+    radio: FocusableRadio,
+    #[focus(enable)]
+    slider: FocusableSlider,
+    #[focus(enable)]
+    pick_list: FocusablePickList<u8>,
+}
+
+fn test_focus() {
+    let mut example = Example::default();
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.button.is_focused());
+    assert!(!example.text_input.is_focused());
+    assert_eq!(example.focus(Direction::Right), State::Ignored);
+
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.checkbox.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.radio.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.slider.has_focus());
+    let _ = example.focus(Direction::Forwards);
+    assert!(example.pick_list.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+    assert!(!example.has_focus());
+
+    let _ = example.focus(Direction::Last);
+    assert!(example.pick_list.has_focus());
+    example.blur();
+    assert!(!example.pick_list.has_focus());
+
+    // The wrapped state is still accessible.
+    let _: &mut button::State = &mut example.button;
+    let _: slider::State = Focusable::new(slider::State::new()).into_inner();
+}
+
+fn test_action() {
+    let mut example = Example::default();
+
+    // Unfocused widgets don't react to the keyboard.
+    assert_eq!(example.button.action(KeyCode::Space), None);
+
+    let _ = example.focus_path(&iced_focus::FocusPath::new().name("button"));
+    assert_eq!(example.button.action(KeyCode::Space), Some(Action::Press));
+    assert_eq!(example.button.action(KeyCode::Enter), Some(Action::Press));
+    assert_eq!(example.button.action(KeyCode::A), None);
+    assert_eq!(example.checkbox.action(KeyCode::Space), None);
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.checkbox.action(KeyCode::Space), Some(Action::Toggle));
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.radio.action(KeyCode::Space), Some(Action::Press));
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.slider.action(KeyCode::Right), Some(Action::Increment));
+    assert_eq!(example.slider.action(KeyCode::Down), Some(Action::Decrement));
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.pick_list.action(KeyCode::Down), Some(Action::Next));
+    assert_eq!(example.pick_list.action(KeyCode::Up), Some(Action::Previous));
+}
+
+fn test_apply() {
+    assert_eq!(Action::Increment.step(0.5, 0.25, 0.0..=1.0), 0.75);
+    assert_eq!(Action::Increment.step(0.9, 0.25, 0.0..=1.0), 1.0);
+    assert_eq!(Action::Decrement.step(0.1, 0.25, 0.0..=1.0), 0.0);
+    assert_eq!(Action::Press.step(0.5, 0.25, 0.0..=1.0), 0.5);
+
+    let options = [1, 2, 3];
+    assert_eq!(Action::Next.select(&options, Some(&1)), Some(&2));
+    assert_eq!(Action::Next.select(&options, Some(&3)), Some(&3));
+    assert_eq!(Action::Previous.select(&options, Some(&2)), Some(&1));
+    assert_eq!(Action::Previous.select(&options, Some(&1)), Some(&1));
+    assert_eq!(Action::Next.select(&options, None), Some(&1));
+    assert_eq!(Action::Toggle.select(&options, Some(&1)), None);
+    assert_eq!(Action::Next.select::<u8>(&[], None), None);
+}
+
+fn test_indicator() {
+    let mut example = Example::default();
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+    let style = example.button.indicator(DefaultStyle);
+    let unfocused = button::StyleSheet::active(&style);
+    assert_eq!(
+        unfocused.border_width,
+        button::StyleSheet::active(&DefaultStyle).border_width
+    );
+
+    let _ = example.focus_path(&iced_focus::FocusPath::new().name("button"));
+    let style = example.button.indicator(DefaultStyle).color(red).width(3.0);
+    assert_eq!(button::StyleSheet::active(&style).border_color, red);
+    assert_eq!(button::StyleSheet::pressed(&style).border_width, 3.0);
+
+    let _ = example.focus(Direction::Forwards);
+    let style = example.checkbox.indicator(DefaultStyle).color(red);
+    assert_eq!(checkbox::StyleSheet::hovered(&style, true).border_color, red);
+
+    let _ = example.focus(Direction::Forwards);
+    let style = example.radio.indicator(DefaultStyle).color(red);
+    assert_eq!(radio::StyleSheet::active(&style).border_color, red);
+
+    let _ = example.focus(Direction::Forwards);
+    let style = example.slider.indicator(DefaultStyle).color(red);
+    assert_eq!(slider::StyleSheet::dragging(&style).handle.border_color, red);
+
+    let _ = example.focus(Direction::Forwards);
+    let style = example.pick_list.indicator(DefaultStyle).color(red);
+    assert_eq!(pick_list::StyleSheet::active(&style).border_color, red);
+
+    // The indicator can be passed to the widgets.
+    let _: Box<dyn button::StyleSheet> = example.button.indicator(DefaultStyle).into();
+}
+
+fn main() {
+    test_focus();
+    test_action();
+    test_apply();
+    test_indicator();
+}
@@ -0,0 +1,127 @@
+use iced::text_input;
+use iced_focus::focus_indicator::{self, FocusIndicator};
+use iced_focus::{Direction, Focus};
+use iced_graphics::{Backend, Primitive};
+use iced_native::{
+    layout, renderer, Color, Column, Element, Layout, Length, Point, Rectangle, Size, Space,
+};
+
+/// A backend without any output to draw the primitives headless.
+struct Headless;
+
+impl Backend for Headless {}
+
+type Renderer = iced_graphics::Renderer<Headless>;
+
+fn draw(indicator: FocusIndicator<'_, (), Renderer>) -> (Rectangle, Primitive) {
+    let mut renderer = Renderer::new(Headless);
+    let element: Element<'_, (), Renderer> = indicator.into();
+    let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+    let node = element.layout(&renderer, &limits);
+    let layout = Layout::new(&node);
+    let viewport = layout.bounds();
+
+    let (primitive, _) = element.draw(
+        &mut renderer,
+        &iced_graphics::Defaults::default(),
+        layout,
+        Point::ORIGIN,
+        &viewport,
+    );
+
+    (layout.bounds(), primitive)
+}
+
+fn content() -> Space {
+    Space::new(Length::Units(20), Length::Units(10))
+}
+
+fn test_unfocused() {
+    let state = text_input::State::new();
+    let (bounds, primitive) = draw(FocusIndicator::new(state.has_focus(), content()));
+
+    // The content is padded by the thickness of the ring.
+    assert_eq!(bounds, Rectangle::new(Point::ORIGIN, Size::new(24.0, 14.0)));
+    assert!(matches!(primitive, Primitive::None));
+}
+
+fn test_focused() {
+    let mut state = text_input::State::new();
+    let _ = Focus::focus(&mut state, Direction::Forwards);
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+    let indicator = FocusIndicator::new(state.has_focus(), content())
+        .color(red)
+        .width(3.0)
+        .radius(4.0)
+        .padding(5);
+    let (bounds, primitive) = draw(indicator);
+
+    assert_eq!(bounds, Rectangle::new(Point::ORIGIN, Size::new(30.0, 20.0)));
+    match primitive {
+        Primitive::Group { primitives } => match primitives.as_slice() {
+            [Primitive::None, Primitive::Quad {
+                bounds: ring,
+                border_radius,
+                border_width,
+                border_color,
+                ..
+            }] => {
+                assert_eq!(*ring, bounds);
+                assert_eq!(*border_radius, 4.0);
+                assert_eq!(*border_width, 3.0);
+                assert_eq!(*border_color, red);
+            }
+            _ => panic!("Expected the content and the ring."),
+        },
+        _ => panic!("Expected a group of primitives."),
+    }
+}
+
+fn test_style() {
+    let style = focus_indicator::Style {
+        color: Color::BLACK,
+        width: 1.0,
+        radius: 0.0,
+    };
+    let state = vec![text_input::State::focused()];
+    let focused = state.has_focus();
+    let (_, primitive) = draw(FocusIndicator::new(focused, content()).style(style));
+
+    match primitive {
+        Primitive::Group { primitives } => {
+            assert!(matches!(
+                primitives[1],
+                Primitive::Quad { border_width, .. } if (border_width - 1.0).abs() < f32::EPSILON
+            ));
+        }
+        _ => panic!("Expected a group of primitives."),
+    }
+}
+
+fn test_null_renderer() {
+    let state = text_input::State::focused();
+    let content = Column::new()
+        .width(Length::Units(20))
+        .height(Length::Units(10));
+    let indicator: FocusIndicator<'_, (), renderer::Null> =
+        FocusIndicator::new(state.has_focus(), content);
+    let mut renderer = renderer::Null::new();
+    let element: Element<'_, (), renderer::Null> = indicator.into();
+    let node = element.layout(
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+    );
+    let layout = Layout::new(&node);
+    let viewport = layout.bounds();
+
+    assert_eq!(layout.bounds().size(), Size::new(24.0, 14.0));
+    element.draw(&mut renderer, &(), layout, Point::ORIGIN, &viewport);
+}
+
+fn main() {
+    test_unfocused();
+    test_focused();
+    test_style();
+    test_null_renderer();
+}
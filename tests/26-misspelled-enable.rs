@@ -0,0 +1,10 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enabel)]
+    text_input: text_input::State,
+}
+
+fn main() {}
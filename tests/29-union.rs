@@ -0,0 +1,9 @@
+use iced_focus::Focus;
+
+#[derive(Focus)]
+union Example {
+    number: u32,
+    float: f32,
+}
+
+fn main() {}
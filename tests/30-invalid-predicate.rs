@@ -0,0 +1,11 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+struct Example {
+    enable: bool,
+    #[focus(enable = "self.enable == true")]
+    text_input: text_input::State,
+}
+
+fn main() {}
@@ -0,0 +1,127 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Default, Focus)]
+struct Example {
+    flag: bool,
+    locked: bool,
+    level: u8,
+    #[focus(enable(self.flag && !self.locked))]
+    text_input_one: text_input::State,
+    #[focus(enable(self.level_at_least(2)))]
+    text_input_two: text_input::State,
+    #[focus(enable = "self.is_unlocked", order = 0)]
+    text_input_three: text_input::State,
+}
+
+impl Example {
+    fn level_at_least(&self, level: u8) -> bool {
+        self.level >= level
+    }
+
+    fn is_unlocked(&self) -> bool {
+        !self.locked
+    }
+}
+
+fn test_struct() {
+    let mut example = Example::default();
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_three.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Returned
+    );
+    assert!(!example.has_focus());
+
+    example.flag = true;
+    example.level = 2;
+    assert_eq!(
+        example.focus(iced_focus::Direction::Last),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_two.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::Backwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_one.has_focus());
+
+    example.locked = true;
+    assert!(!example.has_focus());
+
+    example.sanitize();
+    assert!(!example.text_input_one.has_focus());
+
+    assert_eq!(
+        example.focus(iced_focus::Direction::First),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_two.has_focus());
+}
+
+#[derive(Focus)]
+enum ExampleEnum {
+    Input {
+        #[focus(enable(self.is_enabled()))]
+        text_input: text_input::State,
+        enabled: bool,
+    },
+    Inputs(
+        #[focus(enable(matches!(self, ExampleEnum::Inputs(_, 2..))))] Vec<text_input::State>,
+        usize,
+    ),
+}
+
+impl ExampleEnum {
+    fn is_enabled(&self) -> bool {
+        matches!(self, ExampleEnum::Input { enabled: true, .. })
+    }
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Input {
+        text_input: text_input::State::new(),
+        enabled: false,
+    };
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Ignored
+    );
+
+    if let ExampleEnum::Input {
+        ref mut enabled, ..
+    } = example
+    {
+        *enabled = true;
+    }
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.has_focus());
+
+    let mut example = ExampleEnum::Inputs(vec![text_input::State::new(); 2], 1);
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Ignored
+    );
+
+    let mut example = ExampleEnum::Inputs(vec![text_input::State::new(); 2], 2);
+    assert_eq!(
+        example.focus(iced_focus::Direction::Forwards),
+        iced_focus::State::Kept
+    );
+    assert!(example.has_focus());
+}
+
+fn main() {
+    test_struct();
+    test_enum();
+}
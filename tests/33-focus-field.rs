@@ -0,0 +1,200 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Default, Focus)]
+#[focus(fields)]
+pub struct Example {
+    #[focus(enable, order = 1)]
+    text_input_one: text_input::State,
+    #[focus(enable = "self.enable_two")]
+    text_input_two: text_input::State,
+    #[focus(enable, order = 0)]
+    r#type: Vec<text_input::State>,
+    enabled: bool,
+}
+
+impl Example {
+    fn enable_two(&self) -> bool {
+        self.enabled
+    }
+}
+
+fn test_struct() {
+    let mut example = Example::default();
+    assert_eq!(example.focused_field(), None);
+
+    assert_eq!(
+        example.focus_field(ExampleFocusField::TextInputOne),
+        iced_focus::State::Kept
+    );
+    assert!(example.text_input_one.has_focus());
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleFocusField::TextInputOne)
+    );
+    assert!(example.is_field_focused(ExampleFocusField::TextInputOne));
+    assert!(!example.is_field_focused(ExampleFocusField::TextInputTwo));
+
+    // Disabled fields can't be focused.
+    assert_eq!(
+        example.focus_field(ExampleFocusField::TextInputTwo),
+        iced_focus::State::Ignored
+    );
+    assert!(example.text_input_one.has_focus());
+
+    example.enabled = true;
+    assert_eq!(
+        example.focus_field(ExampleFocusField::TextInputTwo),
+        iced_focus::State::Kept
+    );
+    assert!(!example.text_input_one.has_focus());
+    assert!(example.text_input_two.has_focus());
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleFocusField::TextInputTwo)
+    );
+
+    // Empty collections can't be focused.
+    assert_eq!(
+        example.focus_field(ExampleFocusField::Type),
+        iced_focus::State::Ignored
+    );
+
+    example.r#type.push(text_input::State::new());
+    assert_eq!(
+        example.focus_field(ExampleFocusField::Type),
+        iced_focus::State::Kept
+    );
+    assert!(example.r#type[0].has_focus());
+    assert!(example.is_field_focused(ExampleFocusField::Type));
+
+    example.blur();
+    assert_eq!(example.focused_field(), None);
+}
+
+#[derive(Default, Focus)]
+#[focus(fields)]
+struct ExampleTuple(
+    String,
+    #[focus(enable)] text_input::State,
+    #[focus(enable)] text_input::State,
+);
+
+fn test_tuple_struct() {
+    let mut example = ExampleTuple::default();
+
+    assert_eq!(
+        example.focus_field(ExampleTupleFocusField::Field2),
+        iced_focus::State::Kept
+    );
+    assert!(!example.1.has_focus());
+    assert!(example.2.has_focus());
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleTupleFocusField::Field2)
+    );
+
+    let _ = example.focus(iced_focus::Direction::Backwards);
+    assert!(example.is_field_focused(ExampleTupleFocusField::Field1));
+}
+
+#[derive(Focus)]
+#[focus(fields)]
+enum ExampleEnum {
+    Unit,
+    Login {
+        #[focus(enable)]
+        name: text_input::State,
+        #[focus(enable)]
+        password: text_input::State,
+    },
+    Search(#[focus(enable)] text_input::State),
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Unit;
+    assert_eq!(
+        example.focus_field(ExampleEnumFocusField::LoginName),
+        iced_focus::State::Ignored
+    );
+    assert_eq!(example.focused_field(), None);
+
+    let mut example = ExampleEnum::Login {
+        name: text_input::State::new(),
+        password: text_input::State::new(),
+    };
+
+    // Fields of other variants can't be focused.
+    assert_eq!(
+        example.focus_field(ExampleEnumFocusField::SearchField0),
+        iced_focus::State::Ignored
+    );
+    assert!(!example.has_focus());
+
+    assert_eq!(
+        example.focus_field(ExampleEnumFocusField::LoginPassword),
+        iced_focus::State::Kept
+    );
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleEnumFocusField::LoginPassword)
+    );
+    assert!(!example.is_field_focused(ExampleEnumFocusField::LoginName));
+
+    let mut example = ExampleEnum::Search(text_input::State::new());
+    assert_eq!(
+        example.focus_field(ExampleEnumFocusField::SearchField0),
+        iced_focus::State::Kept
+    );
+    assert!(example.is_field_focused(ExampleEnumFocusField::SearchField0));
+}
+
+#[derive(Focus)]
+#[focus(fields)]
+struct ExampleGeneric<T: Focus> {
+    #[focus(enable)]
+    inner: T,
+}
+
+fn test_generic() {
+    let mut example = ExampleGeneric {
+        inner: text_input::State::new(),
+    };
+    assert_eq!(
+        example.focus_field(ExampleGenericFocusField::Inner),
+        iced_focus::State::Kept
+    );
+    assert!(example.is_field_focused(ExampleGenericFocusField::Inner));
+}
+
+// Without `focus(fields)` no companion is generated, so its names stay available.
+#[derive(Default, Focus)]
+struct Plain {
+    #[focus(enable)]
+    text_input: text_input::State,
+}
+
+#[allow(dead_code)]
+enum PlainFocusField {
+    TextInput,
+}
+
+impl Plain {
+    fn focus_field(&mut self) -> iced_focus::State {
+        self.focus(iced_focus::Direction::First)
+    }
+}
+
+fn test_without_fields() {
+    let mut example = Plain::default();
+    assert_eq!(example.focus_field(), iced_focus::State::Kept);
+    assert!(example.text_input.has_focus());
+}
+
+fn main() {
+    test_struct();
+    test_tuple_struct();
+    test_enum();
+    test_generic();
+    test_without_fields();
+}
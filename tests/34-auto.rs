@@ -0,0 +1,124 @@
+use iced::text_input;
+use iced_focus::{Focus, FocusPath};
+
+#[derive(Default, Focus)]
+struct Nested {
+    #[focus(enable)]
+    text_input: text_input::State,
+}
+
+#[derive(Default, Focus)]
+#[focus(auto, fields)]
+struct Example<T: Focus> {
+    text: String,
+    number: u32,
+    text_input: text_input::State,
+    nested: Nested,
+    vec: Vec<text_input::State>,
+    option: Option<text_input::State>,
+    array: [text_input::State; 2],
+    #[focus(skip)]
+    skipped: text_input::State,
+    #[focus(enable = "self.enable_disabled")]
+    disabled: text_input::State,
+    // Only annotated fields are part of the companion enum.
+    #[focus(enable)]
+    generic: T,
+}
+
+impl<T: Focus> Example<T> {
+    fn enable_disabled(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Focus)]
+#[focus(auto, fields)]
+enum ExampleEnum {
+    Unit,
+    Named {
+        text: String,
+        #[focus(enable)]
+        text_input: text_input::State,
+    },
+    Unnamed(String, #[focus(skip)] text_input::State, text_input::State),
+}
+
+fn test_struct() {
+    let mut example = Example::<text_input::State> {
+        vec: vec![text_input::State::new()],
+        option: Some(text_input::State::new()),
+        ..Example::default()
+    };
+
+    let expected = [
+        FocusPath::new().name("text_input"),
+        FocusPath::new().name("nested").name("text_input"),
+        FocusPath::new().name("vec").index(0),
+        FocusPath::new().name("option"),
+        FocusPath::new().name("array").index(0),
+        FocusPath::new().name("array").index(1),
+        FocusPath::new().name("generic"),
+    ];
+
+    for path in &expected {
+        let _ = example.focus(iced_focus::Direction::Forwards);
+        assert_eq!(example.focused_path().as_ref(), Some(path));
+    }
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert!(!example.has_focus());
+    assert!(!example.skipped.has_focus());
+    assert!(!example.disabled.has_focus());
+
+    // Fields without `Focus` implementation are ignored.
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("text")),
+        iced_focus::State::Ignored
+    );
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("skipped")),
+        iced_focus::State::Ignored
+    );
+
+    assert_eq!(
+        example.focus_field(ExampleFocusField::Generic),
+        iced_focus::State::Kept
+    );
+    assert!(example.generic.has_focus());
+    assert_eq!(example.focused_field(), Some(ExampleFocusField::Generic));
+
+    example.blur();
+    assert!(!example.has_focus());
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Named {
+        text: String::new(),
+        text_input: text_input::State::new(),
+    };
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleEnumFocusField::NamedTextInput)
+    );
+
+    let mut example = ExampleEnum::Unnamed(
+        String::new(),
+        text_input::State::new(),
+        text_input::State::new(),
+    );
+
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert_eq!(example.focused_path(), Some(FocusPath::new().index(2)));
+
+    let mut example = ExampleEnum::Unit;
+    let _ = example.focus(iced_focus::Direction::Forwards);
+    assert!(!example.has_focus());
+}
+
+fn main() {
+    test_struct();
+    test_enum();
+}
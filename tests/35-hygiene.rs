@@ -0,0 +1,205 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusManager, FocusPath, State};
+
+// The fields are named like the identifiers used by the generated code.
+#[derive(Default, Focus)]
+#[focus(cycle, fields)]
+struct Example {
+    #[focus(enable)]
+    direction: text_input::State,
+    #[focus(enable(self.fields_enabled))]
+    fields: text_input::State,
+    #[focus(enable)]
+    path: Vec<text_input::State>,
+    #[focus(enable)]
+    current: text_input::State,
+    #[focus(enable = "self.rest_enabled")]
+    rest: text_input::State,
+    #[focus(enable)]
+    segments: text_input::State,
+    #[focus(enable)]
+    state: text_input::State,
+    #[focus(enable)]
+    b_1: text_input::State,
+    #[focus(enable)]
+    field_0: text_input::State,
+    fields_enabled: bool,
+}
+
+impl Example {
+    fn rest_enabled(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Focus)]
+#[focus(fields)]
+enum ExampleEnum {
+    Named {
+        #[focus(enable)]
+        direction: text_input::State,
+        #[focus(enable = "self.fields_enabled")]
+        fields: text_input::State,
+        #[focus(enable)]
+        path: text_input::State,
+        #[focus(enable)]
+        current: text_input::State,
+        #[focus(enable)]
+        rest: text_input::State,
+        #[focus(enable)]
+        segments: text_input::State,
+        enabled: bool,
+    },
+    Unnamed(
+        #[focus(enable)] text_input::State,
+        #[focus(enable)] text_input::State,
+    ),
+}
+
+impl ExampleEnum {
+    fn fields_enabled(&self) -> bool {
+        matches!(self, Self::Named { enabled: true, .. })
+    }
+}
+
+#[derive(Focus)]
+#[focus(group)]
+enum ExampleGroup {
+    Named {
+        #[focus(enable)]
+        direction: text_input::State,
+        #[focus(enable)]
+        fields: text_input::State,
+        #[focus(memory)]
+        memory: Option<FocusPath>,
+    },
+}
+
+#[derive(Default, Focus)]
+#[focus(auto)]
+struct ExampleAuto {
+    direction: text_input::State,
+    element: text_input::State,
+    path: String,
+}
+
+fn test_struct() {
+    let mut example = Example {
+        path: vec![text_input::State::new()],
+        ..Example::default()
+    };
+
+    let expected = [
+        FocusPath::new().name("direction"),
+        FocusPath::new().name("path").index(0),
+        FocusPath::new().name("current"),
+        FocusPath::new().name("rest"),
+        FocusPath::new().name("segments"),
+        FocusPath::new().name("state"),
+        FocusPath::new().name("b_1"),
+        FocusPath::new().name("field_0"),
+        FocusPath::new().name("direction"),
+    ];
+
+    for path in &expected {
+        assert_eq!(example.focus(Direction::Forwards), State::Kept);
+        assert_eq!(example.focused_path().as_ref(), Some(path));
+    }
+
+    example.fields_enabled = true;
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("fields")),
+        State::Kept
+    );
+    assert!(example.fields.has_focus());
+    assert!(example.has_focus_at(&FocusPath::new().name("fields")));
+    assert_eq!(example.focused_field(), Some(ExampleFocusField::Fields));
+
+    example.fields_enabled = false;
+    example.sanitize();
+    assert!(!example.has_focus());
+
+    let mut manager = FocusManager::new(example);
+    assert_eq!(manager.focus(Direction::Forwards), State::Kept);
+    assert_eq!(manager.focus(Direction::Forwards), State::Kept);
+    assert_eq!(
+        manager.focused_path(),
+        Some(FocusPath::new().name("path").index(0))
+    );
+}
+
+fn test_enum() {
+    let mut example = ExampleEnum::Named {
+        direction: text_input::State::new(),
+        fields: text_input::State::new(),
+        path: text_input::State::new(),
+        current: text_input::State::new(),
+        rest: text_input::State::new(),
+        segments: text_input::State::new(),
+        enabled: false,
+    };
+
+    for name in &["direction", "path", "current", "rest", "segments"] {
+        assert_eq!(example.focus(Direction::Forwards), State::Kept);
+        assert_eq!(example.focused_path(), Some(FocusPath::new().name(name)));
+    }
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+
+    if let ExampleEnum::Named { enabled, .. } = &mut example {
+        *enabled = true;
+    }
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("fields")),
+        State::Kept
+    );
+    assert!(example.has_focus_at(&FocusPath::new().name("fields")));
+    assert_eq!(
+        example.focused_field(),
+        Some(ExampleEnumFocusField::NamedFields)
+    );
+
+    example.blur();
+    assert!(!example.has_focus());
+
+    let mut example = ExampleEnum::Unnamed(text_input::State::new(), text_input::State::new());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert_eq!(example.focused_path(), Some(FocusPath::new().index(1)));
+}
+
+fn test_group() {
+    let mut example = ExampleGroup::Named {
+        direction: text_input::State::new(),
+        fields: text_input::State::new(),
+        memory: None,
+    };
+
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert_eq!(example.focus(Direction::Right), State::Kept);
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("fields"))
+    );
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("fields"))
+    );
+}
+
+fn test_auto() {
+    let mut example = ExampleAuto::default();
+
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.direction.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.element.has_focus());
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+}
+
+fn main() {
+    test_struct();
+    test_enum();
+    test_group();
+    test_auto();
+}
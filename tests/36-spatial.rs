@@ -0,0 +1,183 @@
+use iced::text_input;
+use iced_focus::spatial::{self, Registry};
+use iced_focus::{Direction, Focus, FocusPath, State};
+use iced_native::{layout, renderer, Column, Element, Layout, Length, Point, Rectangle, Size};
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+fn test_rank() {
+    //  a  b
+    //  c     d
+    //     e
+    let a = rect(0.0, 0.0, 10.0, 10.0);
+    let candidates = vec![
+        ("b", rect(20.0, 0.0, 10.0, 10.0)),
+        ("c", rect(0.0, 20.0, 10.0, 10.0)),
+        ("d", rect(40.0, 20.0, 10.0, 10.0)),
+        ("e", rect(20.0, 40.0, 10.0, 10.0)),
+    ];
+
+    assert_eq!(
+        spatial::rank(a, Direction::Right, candidates.clone()),
+        vec!["b", "d", "e"]
+    );
+    assert_eq!(
+        spatial::rank(a, Direction::Down, candidates.clone()),
+        vec!["c", "e", "d"]
+    );
+    assert!(spatial::rank(a, Direction::Up, candidates.clone()).is_empty());
+    assert!(spatial::rank(a, Direction::Left, candidates.clone()).is_empty());
+    assert!(spatial::rank(a, Direction::Forwards, candidates.clone()).is_empty());
+
+    // Elements of the same column are preferred, even if they are further away.
+    let b = rect(20.0, 0.0, 10.0, 10.0);
+    let candidates = vec![
+        ("near", rect(0.0, 15.0, 10.0, 10.0)),
+        ("far", rect(25.0, 50.0, 10.0, 10.0)),
+    ];
+    assert_eq!(
+        spatial::nearest(b, Direction::Down, candidates),
+        Some("far")
+    );
+
+    // The larger overlap wins between elements at the same distance.
+    let wide = rect(0.0, 0.0, 30.0, 10.0);
+    let candidates = vec![
+        ("left", rect(-5.0, 20.0, 10.0, 10.0)),
+        ("center", rect(10.0, 20.0, 10.0, 10.0)),
+    ];
+    assert_eq!(
+        spatial::nearest(wide, Direction::Down, candidates),
+        Some("center")
+    );
+
+    // Overlapping elements are not in any direction.
+    let candidates = vec![("inside", rect(2.0, 2.0, 5.0, 5.0))];
+    assert_eq!(spatial::nearest(a, Direction::Right, candidates), None);
+}
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    rows: Vec<Vec<text_input::State>>,
+    #[focus(enable(self.enable_footer))]
+    footer: text_input::State,
+    enable_footer: bool,
+}
+
+fn cell(row: usize, column: usize) -> FocusPath {
+    FocusPath::new().name("rows").index(row).index(column)
+}
+
+fn test_registry() {
+    let mut example = Example {
+        rows: vec![vec![text_input::State::new(); 2]; 2],
+        ..Example::default()
+    };
+    let registry = Registry::new();
+
+    for row in 0..2 {
+        for column in 0..2 {
+            registry.insert(
+                cell(row, column),
+                rect(column as f32 * 20.0, row as f32 * 20.0, 10.0, 10.0),
+            );
+        }
+    }
+    registry.insert(FocusPath::new().name("footer"), rect(0.0, 40.0, 30.0, 10.0));
+    assert_eq!(
+        registry.get(&cell(1, 1)),
+        Some(rect(20.0, 20.0, 10.0, 10.0))
+    );
+
+    // Arrows are ignored without a focused element.
+    assert_eq!(
+        registry.focus(&mut example, Direction::Right),
+        State::Ignored
+    );
+
+    // All other directions follow the focus chain.
+    assert_eq!(
+        registry.focus(&mut example, Direction::Forwards),
+        State::Kept
+    );
+    assert!(example.rows[0][0].has_focus());
+
+    assert_eq!(registry.focus(&mut example, Direction::Right), State::Kept);
+    assert!(example.rows[0][1].has_focus());
+    assert_eq!(registry.focus(&mut example, Direction::Down), State::Kept);
+    assert!(example.rows[1][1].has_focus());
+    assert_eq!(registry.focus(&mut example, Direction::Left), State::Kept);
+    assert!(example.rows[1][0].has_focus());
+    assert_eq!(
+        registry.focus(&mut example, Direction::Left),
+        State::Ignored
+    );
+    assert!(example.rows[1][0].has_focus());
+
+    // Disabled elements are skipped.
+    assert_eq!(
+        registry.focus(&mut example, Direction::Down),
+        State::Ignored
+    );
+    example.enable_footer = true;
+    assert_eq!(registry.focus(&mut example, Direction::Down), State::Kept);
+    assert!(example.footer.has_focus());
+    assert!(!example.rows.has_focus());
+
+    assert_eq!(registry.focus(&mut example, Direction::Up), State::Kept);
+    assert!(example.rows[1][0].has_focus());
+
+    // Removed elements can't be selected, so the diagonal element is the nearest one.
+    registry.remove(&cell(0, 0));
+    assert_eq!(registry.focus(&mut example, Direction::Up), State::Kept);
+    assert!(example.rows[0][1].has_focus());
+
+    registry.clear();
+    assert_eq!(registry.get(&cell(1, 1)), None);
+
+    // The bounds of the previous frame are kept until the new frame is drawn.
+    registry.insert(cell(0, 0), rect(0.0, 0.0, 10.0, 10.0));
+    registry.insert(cell(0, 1), rect(20.0, 0.0, 10.0, 10.0));
+    registry.next_frame();
+    assert_eq!(registry.get(&cell(0, 1)), Some(rect(20.0, 0.0, 10.0, 10.0)));
+
+    // Elements that are not drawn in the new frame are dropped.
+    registry.insert(cell(1, 0), rect(0.0, 20.0, 10.0, 10.0));
+    registry.insert(cell(1, 1), rect(20.0, 20.0, 10.0, 10.0));
+    assert_eq!(registry.get(&cell(0, 1)), None);
+    assert_eq!(registry.focus(&mut example, Direction::Up), State::Ignored);
+    assert!(example.rows[0][1].has_focus());
+}
+
+fn test_tracker() {
+    let registry = Registry::new();
+    let content = Column::new()
+        .width(Length::Units(20))
+        .height(Length::Units(10));
+    let mut renderer = renderer::Null::new();
+    let element: Element<'_, (), renderer::Null> = registry
+        .track(FocusPath::new().name("input"), content)
+        .into();
+    let node = element.layout(
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+    );
+    let layout = Layout::new(&node);
+    let viewport = layout.bounds();
+
+    assert_eq!(registry.get(&FocusPath::new().name("input")), None);
+    element.draw(&mut renderer, &(), layout, Point::ORIGIN, &viewport);
+    assert_eq!(
+        registry.get(&FocusPath::new().name("input")),
+        Some(rect(0.0, 0.0, 20.0, 10.0))
+    );
+}
+
+fn main() {
+    test_rank();
+    test_registry();
+    test_tracker();
+}
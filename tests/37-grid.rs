@@ -0,0 +1,199 @@
+use iced::keyboard::{KeyCode, Modifiers};
+use iced::text_input;
+use iced_focus::subscription::KeyBindings;
+use iced_focus::{Direction, Focus, FocusGrid, FocusGroup, FocusPath, State, Wrap};
+
+fn grid(wrap: Wrap) -> FocusGrid<text_input::State> {
+    let rows: [[text_input::State; 3]; 3] = Default::default();
+    FocusGrid::from(rows).wrap(wrap)
+}
+
+fn test_stop() {
+    let mut grid = grid(Wrap::Stop);
+
+    // Arrows are ignored without a focused element.
+    assert_eq!(grid.focus(Direction::Right), State::Ignored);
+
+    // Tab traverses the grid row by row.
+    assert_eq!(grid.focus(Direction::Forwards), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+    for _ in 0..3 {
+        assert_eq!(grid.focus(Direction::Forwards), State::Kept);
+    }
+    assert_eq!(grid.focused_cell(), Some((1, 0)));
+
+    assert_eq!(grid.focus(Direction::Right), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((1, 1)));
+    assert_eq!(grid.focus(Direction::Down), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((2, 1)));
+    assert_eq!(grid.focus(Direction::Up), State::Kept);
+    assert_eq!(grid.focus(Direction::Up), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 1)));
+    assert_eq!(grid.focus(Direction::Left), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+    assert!(!grid[1][1].has_focus());
+
+    // The focus stays at the edges.
+    assert_eq!(grid.focus(Direction::Left), State::Ignored);
+    assert_eq!(grid.focus(Direction::Up), State::Ignored);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+
+    assert_eq!(grid.focus(Direction::RowEnd), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 2)));
+    assert_eq!(grid.focus(Direction::RowEnd), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 2)));
+    assert_eq!(grid.focus(Direction::RowStart), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+
+    assert_eq!(
+        grid.focused_path(),
+        Some(FocusPath::new().index(0).index(0))
+    );
+    assert_eq!(
+        grid.focus_path(&FocusPath::new().index(2).index(2)),
+        State::Kept
+    );
+    assert_eq!(grid.focused_cell(), Some((2, 2)));
+    assert_eq!(grid.focus(Direction::Right), State::Ignored);
+    assert_eq!(grid.focus(Direction::Down), State::Ignored);
+
+    grid.blur();
+    assert_eq!(grid.focused_cell(), None);
+}
+
+fn test_around() {
+    let mut grid = grid(Wrap::Around);
+
+    let _ = grid.focus(Direction::First);
+    assert_eq!(grid.focus(Direction::Left), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 2)));
+    assert_eq!(grid.focus(Direction::Right), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+    assert_eq!(grid.focus(Direction::Up), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((2, 0)));
+    assert_eq!(grid.focus(Direction::Down), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+}
+
+fn test_continue() {
+    let mut grid = grid(Wrap::Continue);
+
+    let _ = grid.focus(Direction::First);
+    assert_eq!(grid.focus(Direction::Left), State::Ignored);
+    assert_eq!(grid.focus(Direction::Up), State::Ignored);
+
+    let _ = grid.focus(Direction::RowEnd);
+    assert_eq!(grid.focus(Direction::Right), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((1, 0)));
+    assert_eq!(grid.focus(Direction::Left), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 2)));
+
+    let _ = grid.focus_path(&FocusPath::new().index(2).index(0));
+    assert_eq!(grid.focus(Direction::Down), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 1)));
+    assert_eq!(grid.focus(Direction::Up), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((2, 0)));
+
+    let _ = grid.focus(Direction::Last);
+    assert_eq!(grid.focus(Direction::Right), State::Ignored);
+    assert_eq!(grid.focus(Direction::Down), State::Ignored);
+    assert_eq!(grid.focused_cell(), Some((2, 2)));
+}
+
+fn test_ragged() {
+    let mut grid = FocusGrid::with_columns(vec![text_input::State::new(); 5], 2);
+    assert_eq!(grid.len(), 3);
+    assert_eq!(grid[2].len(), 1);
+
+    let _ = grid.focus_path(&FocusPath::new().index(1).index(1));
+    assert_eq!(grid.focus(Direction::Down), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((2, 0)));
+    assert_eq!(grid.focus(Direction::Right), State::Ignored);
+}
+
+fn test_skip() {
+    let mut grid = FocusGrid::new(vec![
+        vec![
+            Some(text_input::State::new()),
+            None,
+            Some(text_input::State::new()),
+        ],
+        vec![None, None, Some(text_input::State::new())],
+    ]);
+
+    let _ = grid.focus(Direction::First);
+    assert_eq!(grid.focus(Direction::Right), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 2)));
+    assert_eq!(grid.focus(Direction::RowStart), State::Kept);
+    assert_eq!(grid.focused_cell(), Some((0, 0)));
+    assert_eq!(grid.focus(Direction::Down), State::Ignored);
+    assert_eq!(grid.get(0, 0).map(Focus::has_focus), Some(true));
+}
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    before: text_input::State,
+    #[focus(enable)]
+    grid: FocusGrid<text_input::State>,
+}
+
+fn test_derive() {
+    let mut example = Example {
+        before: text_input::State::new(),
+        grid: grid(Wrap::Stop),
+    };
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Down), State::Ignored);
+    assert!(example.before.has_focus());
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(example.focus(Direction::Down), State::Kept);
+    assert_eq!(
+        example.focused_path(),
+        Some(FocusPath::new().name("grid").index(1).index(0))
+    );
+}
+
+fn test_group() {
+    let mut group = FocusGroup::new(vec![text_input::State::new(); 3]);
+
+    assert_eq!(group.focus(Direction::RowEnd), State::Ignored);
+    let _ = group.focus(Direction::Forwards);
+    assert_eq!(group.focus(Direction::RowEnd), State::Kept);
+    assert!(group[2].has_focus());
+    assert_eq!(group.focus(Direction::RowStart), State::Kept);
+    assert!(group[0].has_focus());
+
+    // A grid inside of a group handles the direction first.
+    let mut group = FocusGroup::new(vec![grid(Wrap::Stop)]);
+    let _ = group.focus(Direction::Forwards);
+    let _ = group.focus(Direction::Down);
+    assert_eq!(group.focus(Direction::RowEnd), State::Kept);
+    assert_eq!(group[0].focused_cell(), Some((1, 2)));
+}
+
+fn test_bindings() {
+    let bindings = KeyBindings::new().home_end();
+
+    assert_eq!(
+        bindings.direction(KeyCode::Home, Modifiers::default()),
+        Some(Direction::RowStart)
+    );
+    assert_eq!(
+        bindings.direction(KeyCode::End, Modifiers::default()),
+        Some(Direction::RowEnd)
+    );
+}
+
+fn main() {
+    test_stop();
+    test_around();
+    test_continue();
+    test_ragged();
+    test_skip();
+    test_derive();
+    test_group();
+    test_bindings();
+}
@@ -0,0 +1,106 @@
+use iced::text_input;
+use iced_focus::change::{self, FocusChange, Reason};
+use iced_focus::{Direction, Focus, FocusPath, State, TrackFocus};
+use iced_native::futures::executor::block_on;
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable(self.enable_email))]
+    email: text_input::State,
+    enable_email: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Message {
+    FocusChanged(FocusChange),
+}
+
+fn name() -> FocusPath {
+    FocusPath::new().name("name")
+}
+
+fn email() -> FocusPath {
+    FocusPath::new().name("email")
+}
+
+fn test_tracked() {
+    let mut example = Example {
+        enable_email: true,
+        ..Example::default()
+    };
+
+    let (state, change) = example.focus_tracked(Direction::Forwards);
+    assert_eq!(state, State::Kept);
+    assert_eq!(
+        change,
+        Some(FocusChange {
+            from: None,
+            to: Some(name()),
+            reason: Reason::Direction(Direction::Forwards),
+        })
+    );
+
+    let (_, change) = example.focus_tracked(Direction::Forwards);
+    let change = change.expect("The focus moved to the email.");
+    assert!(change.lost(&name()));
+    assert!(change.gained(&email()));
+    assert!(!change.gained(&name()));
+
+    // Requests that don't move the focus report no change.
+    let (state, change) = example.focus_tracked(Direction::Left);
+    assert_eq!(state, State::Ignored);
+    assert_eq!(change, None);
+    assert_eq!(example.focus_path_tracked(&email()), (State::Kept, None));
+
+    let (state, change) = example.focus_path_tracked(&name());
+    assert_eq!(state, State::Kept);
+    assert_eq!(change.map(|change| change.reason), Some(Reason::Path));
+
+    let change = example.blur_tracked();
+    assert_eq!(
+        change,
+        Some(FocusChange {
+            from: Some(name()),
+            to: None,
+            reason: Reason::Blur,
+        })
+    );
+    assert_eq!(example.blur_tracked(), None);
+}
+
+fn test_track() {
+    let mut example = Example::default();
+
+    let (state, change) = change::track(&mut example, Reason::Path, |example| {
+        example.focus(Direction::Last)
+    });
+    assert_eq!(state, State::Kept);
+    assert_eq!(change.and_then(|change| change.to), Some(name()));
+}
+
+fn test_command() {
+    let change = FocusChange::between(None, Some(name()), Reason::Path);
+    let messages: Vec<Message> = change::command(change.clone(), Message::FocusChanged)
+        .futures()
+        .into_iter()
+        .map(block_on)
+        .collect();
+    assert_eq!(
+        messages,
+        vec![Message::FocusChanged(change.expect("The paths differ."))]
+    );
+
+    let change = FocusChange::between(Some(name()), Some(name()), Reason::Path);
+    assert_eq!(change, None);
+    assert!(change::command(change, Message::FocusChanged)
+        .futures()
+        .is_empty());
+}
+
+fn main() {
+    test_tracked();
+    test_track();
+    test_command();
+}
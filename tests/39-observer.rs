@@ -0,0 +1,148 @@
+use iced::text_input;
+use iced_focus::{
+    observe_focus, snapshot_focus, Direction, Focus, FocusChange, FocusObserver, FocusPath, Reason,
+    State,
+};
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable)]
+    email: text_input::State,
+    #[focus(enable(self.enable_phone))]
+    phone: text_input::State,
+    #[focus(enable)]
+    rows: Vec<text_input::State>,
+    #[focus(enable)]
+    extra: Option<text_input::State>,
+    enable_phone: bool,
+    observer: FocusObserver,
+}
+
+#[derive(Focus)]
+enum ExampleEnum {
+    Pair(
+        #[focus(enable)] text_input::State,
+        #[focus(enable)] text_input::State,
+    ),
+}
+
+fn name() -> FocusPath {
+    FocusPath::new().name("name")
+}
+
+fn email() -> FocusPath {
+    FocusPath::new().name("email")
+}
+
+fn test_focused_paths() {
+    let mut example = Example {
+        rows: vec![text_input::State::new(), text_input::State::focused()],
+        extra: Some(text_input::State::focused()),
+        ..Example::default()
+    };
+    assert_eq!(
+        example.focused_paths(),
+        vec![
+            FocusPath::new().name("rows").index(1),
+            FocusPath::new().name("extra"),
+        ]
+    );
+
+    example.name.focus();
+    example.extra = None;
+    assert_eq!(
+        example.focused_paths(),
+        vec![name(), FocusPath::new().name("rows").index(1)]
+    );
+
+    // Disabled fields are hidden, just like with `focused_path`.
+    example.blur();
+    example.phone.focus();
+    assert_eq!(example.focused_paths(), Vec::<FocusPath>::new());
+
+    let example = ExampleEnum::Pair(text_input::State::focused(), text_input::State::focused());
+    assert_eq!(
+        example.focused_paths(),
+        vec![FocusPath::new().index(0), FocusPath::new().index(1)]
+    );
+}
+
+fn test_observe() {
+    let mut example = Example {
+        enable_phone: true,
+        ..Example::default()
+    };
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    snapshot_focus(&mut example, |example| &mut example.observer);
+    assert_eq!(example.observer.last(), Some(&name()));
+
+    // Nothing changed since the snapshot.
+    assert_eq!(
+        observe_focus(&mut example, |example| &mut example.observer),
+        None
+    );
+
+    // A click into the email focuses it. The name keeps its focus, as if it was not part of the
+    // view.
+    example.email.focus();
+    let change = observe_focus(&mut example, |example| &mut example.observer);
+    assert_eq!(
+        change,
+        Some(FocusChange {
+            from: Some(name()),
+            to: Some(email()),
+            reason: Reason::External,
+        })
+    );
+    assert!(!example.name.is_focused());
+    assert!(example.email.is_focused());
+    assert_eq!(example.focused_paths(), vec![email()]);
+
+    // The focus chain continues at the clicked element.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.phone.is_focused());
+    snapshot_focus(&mut example, |example| &mut example.observer);
+
+    // A click into the name focuses it and removes the focus from the phone.
+    example.phone.unfocus();
+    example.name.focus();
+    let change = observe_focus(&mut example, |example| &mut example.observer);
+    assert_eq!(
+        change,
+        Some(FocusChange {
+            from: Some(FocusPath::new().name("phone")),
+            to: Some(name()),
+            reason: Reason::External,
+        })
+    );
+    assert_eq!(example.focused_paths(), vec![name()]);
+
+    // Removing the focus outside of iced_focus is reported as well.
+    example.blur();
+    let change = observe_focus(&mut example, |example| &mut example.observer);
+    assert_eq!(change.map(|change| change.to), Some(None));
+    assert_eq!(example.observer.last(), None);
+}
+
+fn test_observe_disabled() {
+    let mut example = Example::default();
+    let _ = example.focus_path(&email());
+    snapshot_focus(&mut example, |example| &mut example.observer);
+
+    // A disabled element loses the focus, so the email keeps it.
+    example.phone.focus();
+    assert_eq!(
+        observe_focus(&mut example, |example| &mut example.observer),
+        None
+    );
+    assert!(example.email.is_focused());
+    assert!(!example.phone.is_focused());
+}
+
+fn main() {
+    test_focused_paths();
+    test_observe();
+    test_observe_disabled();
+}
@@ -0,0 +1,157 @@
+use iced::text_input;
+use iced_focus::diagnose::{self, Violation};
+use iced_focus::{Direction, Focus, FocusChecker, FocusPath, State};
+use std::panic::{self, AssertUnwindSafe};
+
+#[derive(Default, Focus)]
+struct Inner {
+    #[focus(enable)]
+    text_input: text_input::State,
+}
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    name: text_input::State,
+    #[focus(enable(self.enable_email))]
+    email: text_input::State,
+    #[focus(enable(self.enable_email))]
+    inner: Inner,
+    #[focus(enable)]
+    rows: Vec<text_input::State>,
+    #[focus(enable)]
+    extra: Option<text_input::State>,
+    enable_email: bool,
+}
+
+#[derive(Focus)]
+enum ExampleEnum {
+    Pair(
+        #[focus(enable)] text_input::State,
+        #[focus(enable(false))] text_input::State,
+    ),
+}
+
+fn name() -> FocusPath {
+    FocusPath::new().name("name")
+}
+
+fn email() -> FocusPath {
+    FocusPath::new().name("email")
+}
+
+fn test_valid() {
+    let mut example = Example::default();
+    assert_eq!(diagnose::diagnose(&example), vec![]);
+    assert_eq!(diagnose::validate(&example), Ok(()));
+
+    let _ = example.focus(Direction::Forwards);
+    assert_eq!(diagnose::validate(&example), Ok(()));
+    assert_eq!(diagnose::diagnose_path(&example, &name()), None);
+}
+
+fn test_several_focused() {
+    let example = Example {
+        name: text_input::State::focused(),
+        rows: vec![text_input::State::new(), text_input::State::focused()],
+        ..Example::default()
+    };
+    assert_eq!(
+        diagnose::diagnose(&example),
+        vec![Violation::SeveralFocused(vec![
+            name(),
+            FocusPath::new().name("rows").index(1),
+        ])]
+    );
+}
+
+fn test_disabled() {
+    let mut example = Example {
+        email: text_input::State::focused(),
+        inner: Inner {
+            text_input: text_input::State::focused(),
+        },
+        ..Example::default()
+    };
+    assert_eq!(
+        example.disabled_focused_paths(),
+        vec![email(), FocusPath::new().name("inner").name("text_input"),]
+    );
+    assert_eq!(
+        diagnose::diagnose(&example)[0],
+        Violation::Disabled(email())
+    );
+    assert_eq!(
+        diagnose::diagnose(&example)[0].to_string(),
+        "the disabled element `email` has the focus"
+    );
+
+    // Enabling the fields makes both of them visible.
+    example.enable_email = true;
+    assert_eq!(example.disabled_focused_paths(), Vec::<FocusPath>::new());
+    let violations = diagnose::validate(&example).expect_err("Both inputs have the focus.");
+    assert!(matches!(violations[..], [Violation::SeveralFocused(_)]));
+
+    example.enable_email = false;
+    example.sanitize();
+    assert_eq!(diagnose::validate(&example), Ok(()));
+
+    let example = ExampleEnum::Pair(text_input::State::new(), text_input::State::focused());
+    assert_eq!(
+        diagnose::diagnose(&example),
+        vec![Violation::Disabled(FocusPath::new().index(1))]
+    );
+}
+
+fn test_stale() {
+    let mut example = Example {
+        extra: Some(text_input::State::new()),
+        ..Example::default()
+    };
+    let extra = FocusPath::new().name("extra");
+    assert_eq!(example.focus_path(&extra), State::Kept);
+    assert_eq!(diagnose::diagnose_path(&example, &extra), None);
+
+    // The focused element is gone with the `Option`.
+    example.extra = None;
+    assert_eq!(
+        diagnose::diagnose_path(&example, &extra),
+        Some(Violation::Stale(extra))
+    );
+    assert_eq!(diagnose::validate(&example), Ok(()));
+}
+
+fn test_checker() {
+    let mut example = FocusChecker::new(Example::default());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert_eq!(example.focused_path(), Some(name()));
+
+    // An input keeps its focus, if it was not part of the view during a click into another one.
+    example.rows = vec![text_input::State::focused()];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| example.focus(Direction::Left)));
+    assert!(result.is_err());
+
+    example.blur();
+    assert_eq!(example.focused_path(), None);
+
+    // The focused element is gone with the `Option`, which leaves no element with the focus.
+    example.extra = Some(text_input::State::new());
+    let extra = FocusPath::new().name("extra");
+    assert_eq!(example.focus_path(&extra), State::Kept);
+    example.extra = None;
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert_eq!(example.focused_path(), Some(name()));
+    assert_eq!(
+        diagnose::diagnose_path(&*example, &extra),
+        Some(Violation::Stale(extra))
+    );
+    assert_eq!(example.into_inner().focused_path(), Some(name()));
+}
+
+fn main() {
+    test_valid();
+    test_several_focused();
+    test_disabled();
+    test_stale();
+    test_checker();
+}
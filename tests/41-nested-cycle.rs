@@ -0,0 +1,50 @@
+use iced::text_input;
+use iced_focus::{Direction, Focus, FocusPath, State};
+
+#[derive(Default, Focus)]
+#[focus(cycle)]
+struct Inner {
+    #[focus(enable)]
+    text_input_one: text_input::State,
+    #[focus(enable)]
+    text_input_two: text_input::State,
+}
+
+#[derive(Default, Focus)]
+struct Example {
+    #[focus(enable)]
+    inner: Inner,
+    #[focus(enable)]
+    text_input: text_input::State,
+}
+
+fn test_nested_cycle() {
+    let mut example = Example::default();
+
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.inner.text_input_one.is_focused());
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.inner.text_input_two.is_focused());
+
+    // The nested cycle wraps around and never returns the focus to its parent.
+    assert_eq!(example.focus(Direction::Forwards), State::Kept);
+    assert!(example.inner.text_input_one.is_focused());
+    assert!(!example.text_input.is_focused());
+    assert_eq!(example.focus(Direction::Backwards), State::Kept);
+    assert!(example.inner.text_input_two.is_focused());
+
+    // Only a focus request by path leaves the nested cycle.
+    assert_eq!(
+        example.focus_path(&FocusPath::new().name("text_input")),
+        State::Kept
+    );
+    assert!(example.text_input.is_focused());
+    assert!(!example.inner.has_focus());
+
+    // Stepping forwards from the last element doesn't enter the cycle again.
+    assert_eq!(example.focus(Direction::Forwards), State::Returned);
+}
+
+fn main() {
+    test_nested_cycle();
+}
@@ -0,0 +1,13 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+#[focus(group)]
+struct Toolbar {
+    #[focus(enable)]
+    bold: text_input::State,
+    #[focus(enable)]
+    italic: text_input::State,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Default, Focus)]
+#[focus(auto, fields)]
+struct Example {
+    text: String,
+    text_input: text_input::State,
+    #[focus(enable)]
+    annotated: text_input::State,
+}
+
+fn main() {
+    let mut example = Example::default();
+    let _ = example.focus_field(ExampleFocusField::Annotated);
+    let _ = example.focus_field(ExampleFocusField::TextInput);
+}
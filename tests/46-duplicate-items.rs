@@ -0,0 +1,24 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+struct Example {
+    #[focus(enable)]
+    #[focus(enable = "self.is_enabled")]
+    text_input: text_input::State,
+}
+
+#[derive(Focus)]
+struct ExampleSkip {
+    #[focus(enable)]
+    #[focus(skip)]
+    text_input: text_input::State,
+}
+
+#[derive(Focus)]
+struct ExampleOrder {
+    #[focus(enable, order = 0, order = 1)]
+    text_input: text_input::State,
+}
+
+fn main() {}
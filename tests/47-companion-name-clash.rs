@@ -0,0 +1,27 @@
+use iced::text_input;
+use iced_focus::Focus;
+
+#[derive(Focus)]
+#[focus(fields)]
+struct Example {
+    #[focus(enable)]
+    a_b: text_input::State,
+    #[allow(non_snake_case)]
+    #[focus(enable)]
+    aB: text_input::State,
+}
+
+#[derive(Focus)]
+#[focus(fields)]
+enum ExampleEnum {
+    A {
+        #[focus(enable)]
+        b_c: text_input::State,
+    },
+    AB {
+        #[focus(enable)]
+        c: text_input::State,
+    },
+}
+
+fn main() {}
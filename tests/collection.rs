@@ -12,4 +12,40 @@ fn tests() {
     t.pass("tests/09-option.rs");
     t.pass("tests/10-complex.rs");
     t.pass("tests/11-generics.rs");
+    t.pass("tests/12-cycle.rs");
+    t.pass("tests/13-first-last.rs");
+    t.pass("tests/14-focus-path.rs");
+    t.pass("tests/15-focused-path.rs");
+    t.pass("tests/16-blur.rs");
+    t.pass("tests/17-order.rs");
+    t.compile_fail("tests/18-order-duplicate.rs");
+    t.pass("tests/19-group.rs");
+    t.pass("tests/20-trap.rs");
+    t.pass("tests/21-history.rs");
+    t.pass("tests/22-manager.rs");
+    t.pass("tests/23-subscription.rs");
+    t.pass("tests/24-widget.rs");
+    t.pass("tests/25-focus-indicator.rs");
+    t.compile_fail("tests/26-misspelled-enable.rs");
+    t.compile_fail("tests/27-empty-attribute.rs");
+    t.compile_fail("tests/28-predicate-not-str.rs");
+    t.compile_fail("tests/29-union.rs");
+    t.compile_fail("tests/30-invalid-predicate.rs");
+    t.pass("tests/31-enable-expression.rs");
+    t.compile_fail("tests/32-invalid-expression.rs");
+    t.pass("tests/33-focus-field.rs");
+    t.pass("tests/34-auto.rs");
+    t.pass("tests/35-hygiene.rs");
+    t.pass("tests/36-spatial.rs");
+    t.pass("tests/37-grid.rs");
+    t.pass("tests/38-focus-change.rs");
+    t.pass("tests/39-observer.rs");
+    t.pass("tests/40-diagnose.rs");
+    t.pass("tests/41-nested-cycle.rs");
+    t.compile_fail("tests/42-group-without-memory.rs");
+    t.compile_fail("tests/43-auto-field-variant.rs");
+    t.compile_fail("tests/44-skip-before-enable.rs");
+    t.compile_fail("tests/45-enable-before-skip.rs");
+    t.compile_fail("tests/46-duplicate-items.rs");
+    t.compile_fail("tests/47-companion-name-clash.rs");
 }